@@ -33,10 +33,14 @@ impl GetAppId for SubmittedData {
 	}
 }
 
+/// Data extracted from one transaction.
+///
+/// A transaction can carry several blobs (i.e. `submit_data_batch`), each of them with its own
+/// `AppExtrinsic` and `SubmittedData` leaf.
 #[derive(Debug, Default)]
 pub struct ExtractedTxData {
-	pub app_extrinsic: Option<AppExtrinsic>,
-	pub submitted_data: Option<SubmittedData>,
+	pub app_extrinsics: Vec<AppExtrinsic>,
+	pub submitted_data: Vec<SubmittedData>,
 	pub bridge_data: Option<BridgedData>,
 }
 
@@ -143,20 +147,33 @@ impl HeaderExtensionBuilderData {
 		Some(proof)
 	}
 
+	/// Returns the leaf index of the transaction `tx_idx`.
+	/// If the transaction submitted several blobs, it returns the leaf of its first blob.
 	pub fn leaf_idx(&self, tx_idx: u32) -> Option<(usize, SubTrie)> {
+		self.blob_leaf_idx(tx_idx, 0)
+	}
+
+	/// Returns the leaf index of the blob `blob_idx` of the transaction `tx_idx`.
+	///
+	/// Blobs are indexed in the order they were submitted, skipping the empty ones which have no
+	/// leaf. Bridge messages only have the blob `0`.
+	pub fn blob_leaf_idx(&self, tx_idx: u32, blob_idx: u32) -> Option<(usize, SubTrie)> {
+		let blob_idx = usize::try_from(blob_idx).ok()?;
 		if let Some(idx) = self
 			.data_submissions
 			.iter()
 			.position(|s| s.tx_index == tx_idx)
 		{
-			return Some((idx, SubTrie::DataSubmit));
+			let leaf_idx = idx.checked_add(blob_idx)?;
+			let leaf = self.data_submissions.get(leaf_idx)?;
+			return (leaf.tx_index == tx_idx).then_some((leaf_idx, SubTrie::DataSubmit));
 		}
 		if let Some(idx) = self
 			.bridge_messages
 			.iter()
 			.position(|b| b.tx_index == tx_idx)
 		{
-			return Some((idx, SubTrie::Bridge));
+			return (blob_idx == 0).then_some((idx, SubTrie::Bridge));
 		}
 		None
 	}
@@ -191,17 +208,13 @@ impl From<Vec<ExtractedTxData>> for HeaderExtensionBuilderData {
 		let mut app_extrinsics = Vec::new();
 
 		for val in value {
-			if let Some(data_submission) = val.submitted_data {
-				data_submissions.push(data_submission);
-			}
+			data_submissions.extend(val.submitted_data);
 
 			if let Some(bridge_message) = val.bridge_data {
 				bridge_messages.push(bridge_message);
 			}
 
-			if let Some(app_extrinsic) = val.app_extrinsic {
-				app_extrinsics.push(app_extrinsic);
-			}
+			app_extrinsics.extend(val.app_extrinsics);
 		}
 
 		Self {
@@ -174,6 +174,7 @@ pub struct KateRpcMetrics {
 	pub query_proof_execution_time: Histogram,
 	pub query_block_length_execution_time: Histogram,
 	pub query_data_proof_execution_time: Histogram,
+	pub query_app_data_execution_time: Histogram,
 }
 
 impl KateRpcMetrics {
@@ -234,11 +235,24 @@ impl KateRpcMetrics {
 			buckets.to_vec(),
 		)?;
 
+		let buckets = [
+			1000.0, 5000.0, 10000.0, 25000.0, //  1ms, 5ms, 10ms, 25ms
+			50000.0, 75000.0, 100_000.0, 150_000.0, // 50ms, 75ms, 100ms, 150ms
+			200_000.0, 300_000.0, 400_000.0, 500_000.0, // 200ms, 300ms, 400ms, 500ms
+		];
+		let query_app_data_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_app_data_execution_time",
+			"Kate RPC - Query App Data Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
 		Ok(Self {
 			query_rows_execution_time,
 			query_proof_execution_time,
 			query_block_length_execution_time,
 			query_data_proof_execution_time,
+			query_app_data_execution_time,
 		})
 	}
 
@@ -277,6 +291,15 @@ impl KateRpcMetrics {
 				.observe(duration.as_micros() as f64);
 		}
 	}
+
+	pub(crate) fn observe_query_app_data_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_app_data_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
 }
 
 pub struct ImportBlockMetrics {
@@ -328,6 +351,7 @@ pub enum ObserveKind {
 	KateQueryBlockLength,
 	KateQueryProof,
 	KateQueryRows,
+	KateQueryAppData,
 	HETotalExecutionTime,
 	HEGrid,
 	HECommitment,
@@ -366,6 +390,9 @@ impl Drop for MetricObserver {
 			ObserveKind::KateQueryRows => {
 				KateRpcMetrics::observe_query_rows_execution_time(duration)
 			},
+			ObserveKind::KateQueryAppData => {
+				KateRpcMetrics::observe_query_app_data_execution_time(duration)
+			},
 			ObserveKind::HETotalExecutionTime => {
 				HeaderExtensionBuilderMetrics::observe_total_execution_time(duration)
 			},
@@ -131,8 +131,10 @@ where
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use kate_rpc::app_keys::{AppKeys, AppKeysApiServer};
 	use kate_rpc::justifications::{GrandpaJustifications, GrandpaServer};
 	use kate_rpc::metrics::KateApiMetricsServer;
+	use kate_rpc::vector::{Vector, VectorApiServer};
 	use kate_rpc::{Kate, KateApiServer};
 	use mmr_rpc::{Mmr, MmrApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
@@ -248,6 +250,15 @@ where
 		GrandpaJustifications::<C, Block>::new(client.clone()),
 	))?;
 
+	io.merge(AppKeysApiServer::into_rpc(AppKeys::<C, Block>::new(
+		client.clone(),
+	)))?;
+
+	io.merge(VectorApiServer::into_rpc(Vector::<C, Block>::new(
+		client.clone(),
+		kate_rpc_deps.max_cells_size,
+	)))?;
+
 	io.merge(kate_rpc::system::ApiServer::into_rpc(
 		kate_rpc::system::Rpc::<C, Block>::new(client),
 	))?;
@@ -131,8 +131,10 @@ pub fn create_extrinsic(
 		frame_system::CheckEra::<Runtime>::from(Era::mortal(period, best_block.saturated_into())),
 		frame_system::CheckNonce::<Runtime>::from(nonce),
 		frame_system::CheckWeight::<Runtime>::new(),
-		ChargeTransactionPayment::<Runtime>::from(tip),
-		da_control::CheckAppId::<Runtime>::from(app_id),
+		da_control::ChargeAppTransactionPayment::<Runtime>::new(
+			ChargeTransactionPayment::from(tip),
+			da_control::CheckAppId::from(app_id),
+		),
 	);
 
 	let raw_payload = da_runtime::SignedPayload::from_raw(
@@ -147,7 +149,6 @@ pub fn create_extrinsic(
 			(),
 			(),
 			(),
-			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
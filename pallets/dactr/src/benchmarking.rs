@@ -9,19 +9,21 @@ use avail_core::{
 };
 use codec::{Decode, Encode};
 use frame_benchmarking::{
-	impl_benchmark_test_suite, v1::BenchmarkError, v2::*, whitelisted_caller,
+	account, impl_benchmark_test_suite, v1::BenchmarkError, v2::*, whitelisted_caller,
 };
-use frame_support::traits::Get;
+use frame_support::traits::{Currency, Get};
 use frame_system::{
 	limits::BlockLength, native::hosted_header_builder::hosted_header_builder, RawOrigin,
 };
 use scale_info::{StaticTypeInfo, TypeInfo};
 use sp_core::H256;
 use sp_runtime::{
-	traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+	traits::{
+		DispatchInfoOf, Dispatchable, SaturatedConversion, Saturating, SignedExtension, Zero,
+	},
 	transaction_validity::{TransactionValidity, TransactionValidityError},
 };
-use sp_std::{fmt::Debug, iter::repeat, vec, vec::Vec};
+use sp_std::{boxed::Box, fmt::Debug, iter::repeat, vec, vec::Vec};
 
 use crate::pallet::Call as DACall;
 
@@ -84,6 +86,40 @@ where
 	}
 }
 
+/// Gives `who` enough free balance to reserve several application key deposits.
+fn fund_account<T: Config>(who: &T::AccountId) {
+	let amount = T::AppKeyDeposit::get()
+		.saturating_mul(10u32.saturated_into())
+		.saturating_add(T::Currency::minimum_balance());
+	T::Currency::make_free_balance_be(who, amount);
+}
+
+/// Creates a funded caller owning a new application key.
+fn sponsored_app_key<T: Config>() -> Result<(T::AccountId, AppKeyFor<T>, AppId), BenchmarkError> {
+	let caller = whitelisted_caller::<T::AccountId>();
+	fund_account::<T>(&caller);
+	let key = AppKeyFor::<T>::try_from(b"Sponsored".to_vec()).unwrap();
+	Pallet::<T>::create_application_key(RawOrigin::Signed(caller.clone()).into(), key.clone())
+		.map_err(|_| BenchmarkError::Stop("Application key cannot be created"))?;
+	let id = Pallet::<T>::application_key(&key).unwrap().id;
+	Ok((caller, key, id))
+}
+
+/// Creates the largest metadata, and funds `who` to reserve its deposit.
+fn max_app_metadata<T: Config>(who: &T::AccountId) -> AppMetadataFor<T> {
+	let len = T::MaxAppMetadataLength::get();
+	let metadata = AppMetadata {
+		name: generate_bounded(len),
+		namespace: generate_bounded(len),
+		website: generate_bounded(len),
+		encoding: AppDataEncoding::Compressed,
+	};
+
+	let deposit = Pallet::<T>::app_metadata_deposit(&metadata);
+	let _ = T::Currency::deposit_creating(who, deposit);
+	metadata
+}
+
 fn repeat_bytes(byte: u8, len: u32) -> Vec<u8> {
 	repeat(byte).take(len as usize).collect::<Vec<_>>()
 }
@@ -165,6 +201,7 @@ mod benchmarks {
 	#[benchmark]
 	fn create_application_key() -> Result<(), BenchmarkError> {
 		let caller = whitelisted_caller::<T::AccountId>();
+		fund_account::<T>(&caller);
 		let origin = RawOrigin::Signed(caller.clone());
 		let max_key_len = T::MaxAppKeyLength::get();
 		let key = generate_bounded::<AppKeyFor<T>>(max_key_len);
@@ -178,7 +215,8 @@ mod benchmarks {
 			info,
 			Some(AppKeyInfoFor::<T> {
 				owner: caller,
-				id: AppId(10)
+				id: AppId(10),
+				deposit: T::AppKeyDeposit::get(),
 			})
 		);
 
@@ -298,6 +336,263 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn transfer_application_key() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let new_owner = account::<T::AccountId>("new_owner", 0, 0);
+		fund_account::<T>(&caller);
+		fund_account::<T>(&new_owner);
+		let key = AppKeyFor::<T>::try_from(b"Transferable".to_vec()).unwrap();
+		Pallet::<T>::create_application_key(RawOrigin::Signed(caller.clone()).into(), key.clone())
+			.map_err(|_| BenchmarkError::Stop("Application key cannot be created"))?;
+		let key_verify = key.clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, new_owner.clone());
+
+		let info = Pallet::<T>::application_key(key_verify).unwrap();
+		assert_eq!(info.owner, new_owner);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn retire_application_key() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		fund_account::<T>(&caller);
+		let key = AppKeyFor::<T>::try_from(b"Retirable".to_vec()).unwrap();
+		Pallet::<T>::create_application_key(RawOrigin::Signed(caller.clone()).into(), key.clone())
+			.map_err(|_| BenchmarkError::Stop("Application key cannot be created"))?;
+		let id = Pallet::<T>::application_key(&key).unwrap().id;
+		let key_verify = key.clone();
+		// Worst case: the sponsorship pot is returned to the owner.
+		let pot = Pallet::<T>::sponsor_pot_account(id);
+		T::Currency::make_free_balance_be(&pot, T::AppKeyDeposit::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key);
+
+		assert!(Pallet::<T>::application_key(key_verify).is_none());
+		assert!(Pallet::<T>::is_app_id_retired(id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_data_quota() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let quota = AppDataQuota {
+			max_bytes: T::MaxAppDataLength::get(),
+			max_scalars: 1_024,
+		};
+
+		#[extrinsic_call]
+		_(origin, AppId(1), Some(quota));
+
+		assert_eq!(Pallet::<T>::app_data_quota(AppId(1)), Some(quota));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_submit_data_fee_modifier_by_app() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let value = DispatchFeeModifier {
+			weight_maximum_fee: Some(100),
+			weight_fee_divider: Some(99),
+			weight_fee_multiplier: Some(98),
+		};
+
+		#[extrinsic_call]
+		_(origin, AppId(1), Some(value));
+
+		assert_eq!(SubmitDataFeeModifierByApp::<T>::get(AppId(1)), Some(value));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fund_sponsorship() -> Result<(), BenchmarkError> {
+		let (caller, key, id) = sponsored_app_key::<T>()?;
+		let amount = T::AppKeyDeposit::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, amount);
+
+		let pot = Pallet::<T>::sponsor_pot_account(id);
+		assert_eq!(T::Currency::free_balance(&pot), amount);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_sponsorship() -> Result<(), BenchmarkError> {
+		let (caller, key, id) = sponsored_app_key::<T>()?;
+		let amount = T::AppKeyDeposit::get();
+		Pallet::<T>::fund_sponsorship(
+			RawOrigin::Signed(caller.clone()).into(),
+			key.clone(),
+			amount,
+		)
+		.map_err(|_| BenchmarkError::Stop("Sponsorship cannot be funded"))?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, amount);
+
+		let pot = Pallet::<T>::sponsor_pot_account(id);
+		assert!(T::Currency::free_balance(&pot).is_zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_sponsored_allowance() -> Result<(), BenchmarkError> {
+		let (caller, key, id) = sponsored_app_key::<T>()?;
+		let signer = account::<T::AccountId>("signer", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, signer.clone(), 100);
+
+		assert_eq!(SponsoredAllowances::<T>::get(id, &signer), 100);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_sponsored_allowance() -> Result<(), BenchmarkError> {
+		let (caller, key, id) = sponsored_app_key::<T>()?;
+		let signer = account::<T::AccountId>("signer", 0, 0);
+		SponsoredAllowances::<T>::insert(id, &signer, 100);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, signer.clone());
+
+		assert!(!SponsoredAllowances::<T>::contains_key(id, &signer));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_block_length_proposal() -> Result<(), BenchmarkError> {
+		let rows = T::MaxBlockRows::get().0;
+		let cols = T::MaxBlockCols::get().0;
+		let when = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, rows, cols, when);
+
+		assert!(PendingBlockLength::<T>::exists());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_block_length_proposal() -> Result<(), BenchmarkError> {
+		let rows = T::MaxBlockRows::get();
+		let cols = T::MaxBlockCols::get();
+		let block_length =
+			BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO_PERBILL)
+				.map_err(|_| BenchmarkError::Stop("Invalid block length"))?;
+		let when = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		PendingBlockLength::<T>::put((when, block_length));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		assert!(!PendingBlockLength::<T>::exists());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_grid_auto_resize() -> Result<(), BenchmarkError> {
+		let config = GridAutoResizeConfig {
+			target: Perbill::from_percent(50),
+			step: 1,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(config));
+
+		assert_eq!(GridAutoResize::<T>::get(), Some(config));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn enact_pending_block_length() -> Result<(), BenchmarkError> {
+		let rows = T::MaxBlockRows::get();
+		let cols = T::MaxBlockCols::get();
+		let block_length =
+			BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO_PERBILL)
+				.map_err(|_| BenchmarkError::Stop("Invalid block length"))?;
+		let now = frame_system::Pallet::<T>::block_number();
+		PendingBlockLength::<T>::put((now, block_length));
+
+		#[block]
+		{
+			Pallet::<T>::enact_pending_block_length(now);
+		}
+
+		assert!(!PendingBlockLength::<T>::exists());
+		assert_eq!(DynamicBlockLength::<T>::get().rows, rows);
+		Ok(())
+	}
+
+	/// Resizes the grid after averaging `s` utilization samples, and records a new one.
+	#[benchmark]
+	fn auto_resize_block_length(
+		s: Linear<0, { T::GridUtilizationWindow::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let config = GridAutoResizeConfig {
+			target: Perbill::from_percent(50),
+			step: 1,
+		};
+		GridAutoResize::<T>::put(config);
+		GridUtilization::<T>::mutate(|samples| {
+			for _ in 0..s {
+				let _ = samples.try_push(Perbill::one());
+			}
+		});
+		let rows = T::MinBlockRows::get();
+		let cols = T::MaxBlockCols::get();
+		let block_length =
+			BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO_PERBILL)
+				.map_err(|_| BenchmarkError::Stop("Invalid block length"))?;
+		DynamicBlockLength::<T>::put(block_length);
+
+		#[block]
+		{
+			Pallet::<T>::auto_resize_block_length();
+			Pallet::<T>::record_grid_utilization();
+		}
+
+		assert!(!GridUtilization::<T>::get().is_empty());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_metadata() -> Result<(), BenchmarkError> {
+		let (caller, key, id) = sponsored_app_key::<T>()?;
+		let metadata = max_app_metadata::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key, Box::new(metadata.clone()));
+
+		assert_eq!(
+			AppMetadataOf::<T>::get(id).map(|(metadata, _)| metadata),
+			Some(metadata)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_app_metadata() -> Result<(), BenchmarkError> {
+		let (caller, key, id) = sponsored_app_key::<T>()?;
+		let metadata = max_app_metadata::<T>(&caller);
+		Pallet::<T>::set_app_metadata(
+			RawOrigin::Signed(caller.clone()).into(),
+			key.clone(),
+			Box::new(metadata),
+		)
+		.map_err(|_| BenchmarkError::Stop("Application metadata cannot be set"))?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), key);
+
+		assert!(!AppMetadataOf::<T>::contains_key(id));
+		Ok(())
+	}
+
 	#[benchmark(extra)]
 	fn commitment_builder_64(
 		i: Linear<32, { T::MaxBlockRows::get().0 }>,
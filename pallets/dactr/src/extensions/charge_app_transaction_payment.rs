@@ -0,0 +1,565 @@
+use super::DaInvalidTransactionCustomId;
+use crate::{
+	weight_helper, Call as DACall, CheckAppId, Config as DAConfig, Pallet,
+	SubmitDataFeeModifierByApp,
+};
+use avail_core::{traits::GetAppId, AppId};
+
+use codec::{Decode, Encode, HasCompact};
+use frame_support::{
+	dispatch::{DispatchFeeModifier, DispatchInfo, PostDispatchInfo},
+	traits::IsSubType,
+};
+use frame_system::Config as SystemConfig;
+use pallet_multisig::{Call as MultisigCall, Config as MultisigConfig};
+use pallet_proxy::{Call as ProxyCall, Config as ProxyConfig};
+use pallet_scheduler::{Call as SchedulerCall, Config as SchedulerConfig};
+use pallet_transaction_payment::{
+	BalanceOf as TxBalanceOf, ChargeTransactionPayment, Config as TxPaymentConfig,
+	Pallet as TxPayment,
+};
+use pallet_utility::{Call as UtilityCall, Config as UtilityConfig};
+use pallet_vector::{Call as VectorCall, Config as VectorConfig};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, SignedExtension,
+		SignedExtensionMetadata, Zero,
+	},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError},
+	DispatchResult,
+};
+use sp_std::{
+	fmt::{self, Debug, Formatter},
+	vec::Vec,
+};
+
+/// Charge the transaction fee and check the Application Id.
+///
+/// It replaces the `(ChargeTransactionPayment, CheckAppId)` pair of signed extensions, keeping
+/// their encoding and metadata, because the fee is charged before `CheckAppId` sees the `AppId`.
+///
+/// # Transaction Validity
+///
+/// Same as `ChargeTransactionPayment` and `CheckAppId`, but `DataAvailability::submit_data(..)`:
+///  - is charged using the fee modifier of its `AppId` (see `SubmitDataFeeModifierByApp`).
+///  Batches of blobs of applications having such a modifier are rejected.
+///  - is paid by the sponsorship pot of its `AppId` if the signer has a sponsored allowance
+///  (see `SponsoredAllowances`). It falls back to the signer if the pot cannot pay the fee, or
+///  if the fee exceeds `MaxSponsoredFee`. Sponsored transactions with a tip are rejected.
+///
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeAppTransactionPayment<
+	T: DAConfig
+		+ TxPaymentConfig
+		+ UtilityConfig
+		+ MultisigConfig
+		+ ProxyConfig
+		+ SchedulerConfig
+		+ Send
+		+ Sync,
+>(pub ChargeTransactionPayment<T>, pub CheckAppId<T>);
+
+impl<T> ChargeAppTransactionPayment<T>
+where
+	T: DAConfig
+		+ TxPaymentConfig
+		+ VectorConfig
+		+ UtilityConfig
+		+ MultisigConfig
+		+ ProxyConfig
+		+ SchedulerConfig
+		+ Send
+		+ Sync,
+	<T as SystemConfig>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<DACall<T>>,
+	TxBalanceOf<T>: HasCompact,
+{
+	/// utility constructor. Used only in client/factory code.
+	pub fn new(charge: ChargeTransactionPayment<T>, check_app_id: CheckAppId<T>) -> Self {
+		Self(charge, check_app_id)
+	}
+
+	/// Returns the fee modifier used to charge `call`.
+	///
+	/// `DataAvailability::submit_data(..)` uses the modifier of its `AppId`, and any other call
+	/// keeps the one from its dispatch info.
+	///
+	/// `DataAvailability::submit_data_batch(..)` cannot apply a different modifier to each blob,
+	/// so it is rejected if any of its blobs belongs to an application with its own modifier.
+	fn fee_modifier(
+		&self,
+		call: &<T as SystemConfig>::RuntimeCall,
+		info: &DispatchInfo,
+	) -> Result<DispatchFeeModifier, TransactionValidityError> {
+		match call.is_sub_type() {
+			Some(DACall::<T>::submit_data { .. }) => {
+				Ok(weight_helper::submit_data_fee_modifier::<T>(self.app_id()))
+			},
+			Some(DACall::<T>::submit_data_batch { blobs }) => {
+				let has_app_modifier = blobs
+					.iter()
+					.any(|(id, _)| SubmitDataFeeModifierByApp::<T>::contains_key(id));
+				if has_app_modifier {
+					return Err(InvalidTransaction::Custom(
+						DaInvalidTransactionCustomId::BatchWithAppFeeModifier as u8,
+					)
+					.into());
+				}
+				Ok(info.fee_modifier)
+			},
+			_ => Ok(info.fee_modifier),
+		}
+	}
+
+	/// Returns the tip of the transaction.
+	fn tip(&self) -> TxBalanceOf<T> {
+		// `ChargeTransactionPayment` does not expose its tip, which is its only encoded field.
+		<<TxBalanceOf<T> as HasCompact>::Type>::decode(&mut &self.0.encode()[..])
+			.map(Into::into)
+			.unwrap_or_default()
+	}
+
+	/// Returns the sponsorship pot paying the fees of `call` signed by `who`, if any.
+	///
+	/// The pot never pays a tip, so sponsored transactions with a tip are rejected. It does not
+	/// pay fees above `MaxSponsoredFee` either, which are charged to the signer instead.
+	fn sponsor(
+		&self,
+		who: &T::AccountId,
+		call: &<T as SystemConfig>::RuntimeCall,
+		info: &DispatchInfo,
+		len: usize,
+	) -> Result<Option<T::AccountId>, TransactionValidityError> {
+		let pot = match call.is_sub_type() {
+			Some(DACall::<T>::submit_data { .. }) => Pallet::<T>::sponsor_of(self.app_id(), who),
+			_ => None,
+		};
+		let Some(pot) = pot else {
+			return Ok(None);
+		};
+
+		let tip = self.tip();
+		if !tip.is_zero() {
+			return Err(InvalidTransaction::Custom(
+				DaInvalidTransactionCustomId::SponsoredTransactionTip as u8,
+			)
+			.into());
+		}
+
+		let fee = TxPayment::<T>::compute_fee(len.saturated_into(), info, tip);
+		let max_fee = T::MaxSponsoredFee::get();
+		Ok((fee.saturated_into::<u128>() <= max_fee.saturated_into::<u128>()).then_some(pot))
+	}
+}
+
+impl<T> Debug for ChargeAppTransactionPayment<T>
+where
+	T: DAConfig
+		+ TxPaymentConfig
+		+ UtilityConfig
+		+ VectorConfig
+		+ MultisigConfig
+		+ ProxyConfig
+		+ SchedulerConfig
+		+ Send
+		+ Sync,
+{
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "ChargeAppTransactionPayment<{:?}, {:?}>", self.0, self.1)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T> SignedExtension for ChargeAppTransactionPayment<T>
+where
+	T: DAConfig
+		+ TxPaymentConfig
+		+ VectorConfig
+		+ UtilityConfig
+		+ MultisigConfig
+		+ ProxyConfig
+		+ SchedulerConfig
+		+ Send
+		+ Sync,
+	<T as MultisigConfig>::RuntimeCall: IsSubType<VectorCall<T>>
+		+ IsSubType<ProxyCall<T>>
+		+ IsSubType<UtilityCall<T>>
+		+ IsSubType<MultisigCall<T>>
+		+ IsSubType<SchedulerCall<T>>,
+	<T as SchedulerConfig>::RuntimeCall: IsSubType<VectorCall<T>>
+		+ IsSubType<ProxyCall<T>>
+		+ IsSubType<UtilityCall<T>>
+		+ IsSubType<MultisigCall<T>>
+		+ IsSubType<SchedulerCall<T>>,
+	<T as SystemConfig>::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
+		+ IsSubType<DACall<T>>
+		+ IsSubType<UtilityCall<T>>
+		+ IsSubType<VectorCall<T>>
+		+ IsSubType<MultisigCall<T>>
+		+ IsSubType<ProxyCall<T>>
+		+ IsSubType<SchedulerCall<T>>,
+	TxBalanceOf<T>: HasCompact,
+	ChargeTransactionPayment<T>: SignedExtension<
+		AccountId = T::AccountId,
+		Call = <T as SystemConfig>::RuntimeCall,
+		AdditionalSigned = (),
+	>,
+	[u8; 32]: From<<T as SystemConfig>::AccountId>,
+{
+	type AccountId = T::AccountId;
+	type AdditionalSigned = ();
+	type Call = <T as SystemConfig>::RuntimeCall;
+	type Pre = (
+		<ChargeTransactionPayment<T> as SignedExtension>::Pre,
+		DispatchFeeModifier,
+	);
+
+	const IDENTIFIER: &'static str = "ChargeAppTransactionPayment";
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		self.0.additional_signed()?;
+		self.1.additional_signed()
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let info = DispatchInfo {
+			fee_modifier: self.fee_modifier(call, info)?,
+			..*info
+		};
+		let charge = match self.sponsor(who, call, &info, len)? {
+			Some(pot) => self
+				.0
+				.validate(&pot, call, &info, len)
+				.or_else(|_| self.0.validate(who, call, &info, len))?,
+			None => self.0.validate(who, call, &info, len)?,
+		};
+		let check_app_id = self.1.validate(who, call, &info, len)?;
+		Ok(charge.combine_with(check_app_id))
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let fee_modifier = self.fee_modifier(call, info)?;
+		let info = DispatchInfo {
+			fee_modifier,
+			..*info
+		};
+		let sponsor = self.sponsor(who, call, &info, len)?;
+		let Self(charge, check_app_id) = self;
+		let app_id = check_app_id.app_id();
+		let charge_pre = match sponsor {
+			Some(pot) => match charge.clone().pre_dispatch(&pot, call, &info, len) {
+				Ok(charge_pre) => {
+					Pallet::<T>::consume_sponsored_allowance(app_id, who);
+					charge_pre
+				},
+				Err(_) => charge.pre_dispatch(who, call, &info, len)?,
+			},
+			None => charge.pre_dispatch(who, call, &info, len)?,
+		};
+		check_app_id.pre_dispatch(who, call, &info, len)?;
+		Ok((charge_pre, fee_modifier))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let check_app_id_pre = pre.as_ref().map(|_| ());
+		let (charge_pre, info) = match pre {
+			Some((charge_pre, fee_modifier)) => (
+				Some(charge_pre),
+				DispatchInfo {
+					fee_modifier,
+					..*info
+				},
+			),
+			None => (None, *info),
+		};
+		ChargeTransactionPayment::<T>::post_dispatch(charge_pre, &info, post_info, len, result)?;
+		CheckAppId::<T>::post_dispatch(check_app_id_pre, &info, post_info, len, result)
+	}
+
+	fn metadata() -> Vec<SignedExtensionMetadata> {
+		let mut metadata = ChargeTransactionPayment::<T>::metadata();
+		metadata.append(&mut CheckAppId::<T>::metadata());
+		metadata
+	}
+}
+
+impl<T> GetAppId for ChargeAppTransactionPayment<T>
+where
+	T: DAConfig
+		+ TxPaymentConfig
+		+ UtilityConfig
+		+ VectorConfig
+		+ MultisigConfig
+		+ ProxyConfig
+		+ SchedulerConfig
+		+ Send
+		+ Sync,
+{
+	#[inline]
+	fn app_id(&self) -> AppId {
+		self.1.app_id()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use avail_core::AppId;
+	use frame_support::dispatch::{DispatchFeeModifier, DispatchInfo};
+	use frame_system::pallet::Call as SysCall;
+
+	use super::*;
+	use crate::extensions::extensions_mock::{
+		new_test_ext, u8_to_account_id, Balances, MaxSponsoredFee, RuntimeCall, Test,
+	};
+	use crate::pallet::Call as DACall;
+	use crate::{AppDataFor, SponsoredAllowances, SubmitDataFeeModifier};
+	use frame_support::{traits::Currency, weights::Weight};
+	use sp_runtime::transaction_validity::InvalidTransaction;
+
+	const GLOBAL: DispatchFeeModifier = DispatchFeeModifier {
+		weight_maximum_fee: Some(100),
+		weight_fee_divider: None,
+		weight_fee_multiplier: None,
+	};
+
+	const BY_APP: DispatchFeeModifier = DispatchFeeModifier {
+		weight_maximum_fee: None,
+		weight_fee_divider: Some(2),
+		weight_fee_multiplier: None,
+	};
+
+	fn remark_call() -> RuntimeCall {
+		RuntimeCall::System(SysCall::remark { remark: vec![] })
+	}
+
+	fn submit_data_call() -> RuntimeCall {
+		RuntimeCall::DataAvailability(DACall::submit_data {
+			data: vec![1].try_into().unwrap(),
+		})
+	}
+
+	fn submit_data_batch_call(ids: Vec<u32>) -> RuntimeCall {
+		let blobs = ids
+			.into_iter()
+			.map(|id| (AppId(id), AppDataFor::<Test>::truncate_from(vec![1])))
+			.collect();
+		RuntimeCall::DataAvailability(DACall::submit_data_batch { blobs })
+	}
+
+	fn fee_modifier(
+		id: u32,
+		call: RuntimeCall,
+	) -> Result<DispatchFeeModifier, TransactionValidityError> {
+		let ext = ChargeAppTransactionPayment::<Test>::new(
+			ChargeTransactionPayment::from(0),
+			CheckAppId::from(AppId(id)),
+		);
+		let info = DispatchInfo::default();
+
+		new_test_ext().execute_with(|| {
+			SubmitDataFeeModifier::<Test>::put(GLOBAL);
+			SubmitDataFeeModifierByApp::<Test>::insert(AppId(1), BY_APP);
+			ext.fee_modifier(&call, &info)
+		})
+	}
+
+	#[test]
+	fn submit_data_uses_app_fee_modifier() {
+		assert_eq!(fee_modifier(1, submit_data_call()), Ok(BY_APP));
+	}
+
+	#[test]
+	fn submit_data_falls_back_to_global_fee_modifier() {
+		assert_eq!(fee_modifier(0, submit_data_call()), Ok(GLOBAL));
+	}
+
+	#[test]
+	fn submit_data_batch_keeps_its_fee_modifier() {
+		assert_eq!(
+			fee_modifier(0, submit_data_batch_call(vec![0, 2])),
+			Ok(DispatchFeeModifier::default())
+		);
+	}
+
+	#[test]
+	fn submit_data_batch_with_app_fee_modifier_is_rejected() {
+		assert_eq!(
+			fee_modifier(0, submit_data_batch_call(vec![0, 1])),
+			Err(InvalidTransaction::Custom(
+				DaInvalidTransactionCustomId::BatchWithAppFeeModifier as u8
+			)
+			.into())
+		);
+	}
+
+	#[test]
+	fn other_calls_keep_their_fee_modifier() {
+		assert_eq!(
+			fee_modifier(1, remark_call()),
+			Ok(DispatchFeeModifier::default())
+		);
+	}
+
+	#[test]
+	fn metadata_matches_replaced_extensions() {
+		let identifiers = ChargeAppTransactionPayment::<Test>::metadata()
+			.into_iter()
+			.map(|m| m.identifier)
+			.collect::<Vec<_>>();
+		assert_eq!(identifiers, vec!["ChargeTransactionPayment", "CheckAppId"]);
+	}
+
+	#[test]
+	fn encoding_matches_replaced_extensions() {
+		let charge = ChargeTransactionPayment::<Test>::from(42);
+		let check_app_id = CheckAppId::<Test>::from(AppId(7));
+		let ext = ChargeAppTransactionPayment::new(charge.clone(), check_app_id.clone());
+		assert_eq!(ext.encode(), (charge, check_app_id).encode());
+	}
+
+	fn sponsored_pre_dispatch_with(
+		signer: u8,
+		call: RuntimeCall,
+		tip: u64,
+		weight: u64,
+	) -> Result<(), TransactionValidityError> {
+		let ext = ChargeAppTransactionPayment::<Test>::new(
+			ChargeTransactionPayment::from(tip),
+			CheckAppId::from(AppId(1)),
+		);
+		let info = DispatchInfo {
+			weight: Weight::from_parts(weight, 0),
+			..Default::default()
+		};
+		let signer = u8_to_account_id(signer);
+		ext.pre_dispatch(&signer, &call, &info, 10).map(|_| ())
+	}
+
+	fn sponsored_pre_dispatch(
+		signer: u8,
+		call: RuntimeCall,
+	) -> Result<(), TransactionValidityError> {
+		sponsored_pre_dispatch_with(signer, call, 0, 100)
+	}
+
+	#[test]
+	fn sponsored_submit_data_is_paid_by_pot() {
+		new_test_ext().execute_with(|| {
+			let bob = u8_to_account_id(2);
+			let pot = Pallet::<Test>::sponsor_pot_account(AppId(1));
+			Balances::make_free_balance_be(&pot, 1_000);
+			SponsoredAllowances::<Test>::insert(AppId(1), &bob, 1);
+
+			assert_eq!(sponsored_pre_dispatch(2, submit_data_call()), Ok(()));
+			assert!(Balances::free_balance(&pot) < 1_000);
+			assert_eq!(Balances::free_balance(&bob), 0);
+			assert!(!SponsoredAllowances::<Test>::contains_key(AppId(1), &bob));
+
+			// The allowance is consumed, so the signer has to pay the fee.
+			assert_eq!(
+				sponsored_pre_dispatch(2, submit_data_call()),
+				Err(InvalidTransaction::Payment.into())
+			);
+		})
+	}
+
+	#[test]
+	fn sponsorship_falls_back_to_signer() {
+		new_test_ext().execute_with(|| {
+			let alice = u8_to_account_id(1);
+			let alice_balance = Balances::free_balance(&alice);
+			SponsoredAllowances::<Test>::insert(AppId(1), &alice, 1);
+
+			// The pot is empty, so the signer pays the fee.
+			assert_eq!(sponsored_pre_dispatch(1, submit_data_call()), Ok(()));
+			assert!(Balances::free_balance(&alice) < alice_balance);
+			assert_eq!(SponsoredAllowances::<Test>::get(AppId(1), &alice), 1);
+		})
+	}
+
+	#[test]
+	fn only_submit_data_is_sponsored() {
+		new_test_ext().execute_with(|| {
+			let bob = u8_to_account_id(2);
+			let pot = Pallet::<Test>::sponsor_pot_account(AppId(1));
+			Balances::make_free_balance_be(&pot, 1_000);
+			SponsoredAllowances::<Test>::insert(AppId(1), &bob, 1);
+
+			let ext = ChargeAppTransactionPayment::<Test>::new(
+				ChargeTransactionPayment::from(0),
+				CheckAppId::from(AppId(1)),
+			);
+			let info = DispatchInfo::default();
+			assert_eq!(ext.sponsor(&bob, &remark_call(), &info, 10), Ok(None));
+			assert_eq!(
+				ext.sponsor(&bob, &submit_data_call(), &info, 10),
+				Ok(Some(pot))
+			);
+		})
+	}
+
+	#[test]
+	fn sponsored_submit_data_with_tip_is_rejected() {
+		new_test_ext().execute_with(|| {
+			let bob = u8_to_account_id(2);
+			let pot = Pallet::<Test>::sponsor_pot_account(AppId(1));
+			Balances::make_free_balance_be(&pot, 1_000_000);
+			SponsoredAllowances::<Test>::insert(AppId(1), &bob, 1);
+
+			assert_eq!(
+				sponsored_pre_dispatch_with(2, submit_data_call(), 500_000, 100),
+				Err(InvalidTransaction::Custom(
+					DaInvalidTransactionCustomId::SponsoredTransactionTip as u8
+				)
+				.into())
+			);
+			assert_eq!(Balances::free_balance(&pot), 1_000_000);
+			assert_eq!(SponsoredAllowances::<Test>::get(AppId(1), &bob), 1);
+		})
+	}
+
+	#[test]
+	fn sponsored_fee_above_max_is_paid_by_signer() {
+		new_test_ext().execute_with(|| {
+			let alice = u8_to_account_id(1);
+			let alice_balance = Balances::free_balance(&alice);
+			let pot = Pallet::<Test>::sponsor_pot_account(AppId(1));
+			Balances::make_free_balance_be(&pot, 1_000_000);
+			SponsoredAllowances::<Test>::insert(AppId(1), &alice, 1);
+
+			let weight = MaxSponsoredFee::get() + 1;
+			assert_eq!(
+				sponsored_pre_dispatch_with(1, submit_data_call(), 0, weight),
+				Ok(())
+			);
+			assert_eq!(Balances::free_balance(&pot), 1_000_000);
+			assert!(Balances::free_balance(&alice) < alice_balance);
+			assert_eq!(SponsoredAllowances::<Test>::get(AppId(1), &alice), 1);
+		})
+	}
+}
@@ -1,4 +1,4 @@
-use super::MAX_ITERATIONS;
+use super::{DaInvalidTransactionCustomId, MAX_ITERATIONS};
 use crate::{Call as DACall, CheckBatchTransactions, Config as DAConfig, Pallet, LOG_TARGET};
 use avail_core::{traits::GetAppId, AppId, InvalidTransactionCustomId};
 
@@ -33,6 +33,8 @@ use sp_std::{
 ///
 /// Only registered application can be used by transactions.
 ///
+/// Applications with a data quota cannot exceed it in a single block.
+///
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct CheckAppId<
@@ -74,39 +76,70 @@ where
 
 	/// It validates that `AppId` is correct and already registered for the call and potential nested calls.
 	/// Transaction validation:
-	///  - `DataAvailability::submit_data(..)` extrinsic can use `AppId != 0`.
+	///  - `DataAvailability::submit_data(..)` extrinsic can use `AppId != 0`, if it was not retired.
 	///  - `Utility::batch/batch_all/force_batch(..)` extrinsic can use `AppId != 0` If the wrapped calls are ALL `DataAvailability::submit_data(..)`.
 	///  - Any other call must use `AppId == 0`.
 	///  - It also ensures that Kate's evaluation grid can be generated during the header
 	///  production.
+	///  - `DataAvailability::submit_data(..)` cannot exceed the data quota of its `AppId`, if any,
+	///  even wrapped in `Utility` batches.
+	///  - Each blob of `DataAvailability::submit_data_batch(..)` must use a registered and not
+	///  retired `AppId`, and it cannot exceed the data quota of that `AppId`, if any.
 	pub fn do_validate(
 		&self,
 		call: &<T as SystemConfig>::RuntimeCall,
 		len: usize,
 	) -> TransactionValidity {
 		self.ensure_valid_app_id(call)?;
-		if let Some(DACall::<T>::submit_data { .. }) = call.is_sub_type() {
-			let all_extrinsics_len = self
-				.next_all_extrinsics_len(len)
-				.ok_or(InvalidTransaction::ExhaustsResources)?;
-			AllExtrinsicsLen::<T>::put(all_extrinsics_len);
+		match call.is_sub_type() {
+			Some(DACall::<T>::submit_data { .. }) => {
+				Self::update_all_extrinsics_len(&[(self.app_id(), len)])?;
+			},
+			Some(DACall::<T>::submit_data_batch { blobs }) => {
+				let blob_lens = blobs
+					.iter()
+					.map(|(id, data)| {
+						Self::ensure_valid_blob_app_id(*id)?;
+						Ok((*id, data.len()))
+					})
+					.collect::<Result<Vec<_>, TransactionValidityError>>()?;
+				Self::update_all_extrinsics_len(&blob_lens)?;
+			},
+			// `submit_data` calls wrapped in utility batches count against the quota as well, if any.
+			_ if <Pallet<T>>::app_data_quota(self.app_id()).is_some()
+				&& Self::has_nested_submit_data(call)? =>
+			{
+				Self::update_all_extrinsics_len(&[(self.app_id(), len)])?;
+			},
+			_ => (),
 		}
 
 		CheckBatchTransactions::<T>::new().do_validate(call, len)?;
 		Ok(ValidTransaction::default())
 	}
 
-	fn next_all_extrinsics_len(&self, len: usize) -> Option<ExtrinsicLenOf<T>> {
-		let app_id = self.app_id();
-		let len = u32::try_from(len).ok()?;
+	/// Adds `lens` to `AllExtrinsicsLen`, checking the block length and the data quotas.
+	fn update_all_extrinsics_len(lens: &[(AppId, usize)]) -> Result<(), TransactionValidityError> {
+		let all_extrinsics_len =
+			Self::next_all_extrinsics_len(lens).ok_or(InvalidTransaction::ExhaustsResources)?;
+		for (app_id, _) in lens {
+			Self::ensure_app_data_quota(*app_id, &all_extrinsics_len)?;
+		}
+		AllExtrinsicsLen::<T>::put(all_extrinsics_len);
+		Ok(())
+	}
 
+	fn next_all_extrinsics_len(lens: &[(AppId, usize)]) -> Option<ExtrinsicLenOf<T>> {
 		// Get maximum padded length of current block length.
 		let curr_len = DynamicBlockLength::<T>::get();
 		let max_scalars = curr_len.rows.0.checked_mul(curr_len.cols.0)?;
 
 		// Update extrinsics length info.
 		let mut all_extrinsics_len = AllExtrinsicsLen::<T>::get().unwrap_or_default();
-		let _ = all_extrinsics_len.add_padded(app_id, len)?;
+		for (app_id, len) in lens {
+			let len = u32::try_from(*len).ok()?;
+			let _ = all_extrinsics_len.add_padded(*app_id, len)?;
+		}
 
 		// Calculate total padded length
 		let total_scalars = all_extrinsics_len.total_num_scalars()?;
@@ -122,6 +155,73 @@ where
 		}
 	}
 
+	/// Ensures that the data of `AppId` in `all_extrinsics_len` fits into its quota.
+	fn ensure_app_data_quota(
+		app_id: AppId,
+		all_extrinsics_len: &ExtrinsicLenOf<T>,
+	) -> Result<(), TransactionValidityError> {
+		let Some(quota) = <Pallet<T>>::app_data_quota(app_id) else {
+			return Ok(());
+		};
+
+		let app_len = all_extrinsics_len.app_len(app_id);
+		let app_scalars = all_extrinsics_len.app_num_scalars(app_id);
+		if app_len > quota.max_bytes || app_scalars > quota.max_scalars {
+			log::warn!(
+				target: LOG_TARGET,
+				"Data quota of {app_id:?} is exhausted, requested {app_len} bytes and {app_scalars} scalars");
+
+			return Err(InvalidTransaction::Custom(
+				DaInvalidTransactionCustomId::AppDataQuotaExceeded as u8,
+			)
+			.into());
+		}
+
+		Ok(())
+	}
+
+	/// Whether `call` is a utility batch that wraps, at any depth, a `submit_data` call.
+	fn has_nested_submit_data(
+		call: &<T as SystemConfig>::RuntimeCall,
+	) -> Result<bool, TransactionValidityError> {
+		let mut stack = Vec::new();
+		stack.push(call);
+		let mut iterations = 0;
+
+		while let Some(call) = stack.pop() {
+			if let Some(DACall::<T>::submit_data { .. }) = call.is_sub_type() {
+				return Ok(true);
+			}
+			if let Some(
+				UtilityCall::<T>::batch { calls }
+				| UtilityCall::<T>::batch_all { calls }
+				| UtilityCall::<T>::force_batch { calls },
+			) = call.is_sub_type()
+			{
+				iterations += 1;
+				ensure!(
+					iterations < MAX_ITERATIONS,
+					InvalidTransaction::Custom(
+						InvalidTransactionCustomId::MaxRecursionExceeded as u8
+					)
+				);
+				stack.extend(calls.iter().map(|call| call.into_ref()));
+			}
+		}
+
+		Ok(false)
+	}
+
+	/// Ensures that the `AppId` of a `submit_data_batch` blob is registered and not retired.
+	fn ensure_valid_blob_app_id(app_id: AppId) -> Result<(), TransactionValidityError> {
+		ensure!(
+			app_id < <Pallet<T>>::peek_next_application_id()
+				&& !<Pallet<T>>::is_app_id_retired(app_id),
+			InvalidTransaction::Custom(InvalidTransactionCustomId::InvalidAppId as u8)
+		);
+		Ok(())
+	}
+
 	fn ensure_valid_app_id(
 		&self,
 		call: &<T as SystemConfig>::RuntimeCall,
@@ -144,6 +244,10 @@ where
 					self.app_id() < *next_app_id,
 					InvalidTransaction::Custom(InvalidTransactionCustomId::InvalidAppId as u8)
 				);
+				ensure!(
+					!<Pallet<T>>::is_app_id_retired(self.app_id()),
+					InvalidTransaction::Custom(InvalidTransactionCustomId::InvalidAppId as u8)
+				);
 			} else {
 				match call.is_sub_type() {
 					Some(UtilityCall::<T>::batch { calls })
@@ -302,7 +406,7 @@ mod tests {
 
 	use super::*;
 	use crate::extensions::extensions_mock::{new_test_ext, RuntimeCall, Test};
-	use crate::pallet::Call as DACall;
+	use crate::pallet::{AppDataQuota, Call as DACall};
 
 	fn remark_call() -> RuntimeCall {
 		RuntimeCall::System(SysCall::remark { remark: vec![] })
@@ -314,20 +418,147 @@ mod tests {
 		})
 	}
 
+	fn submit_data_batch_call(ids: &[u32]) -> RuntimeCall {
+		let blobs = ids
+			.iter()
+			.map(|id| (AppId(*id), vec![1u8; 8].try_into().unwrap()))
+			.collect();
+		RuntimeCall::DataAvailability(DACall::submit_data_batch { blobs })
+	}
+
 	fn to_invalid_tx(custom_id: InvalidTransactionCustomId) -> TransactionValidity {
 		Err(TransactionValidityError::Invalid(
 			InvalidTransaction::Custom(custom_id as u8),
 		))
 	}
 
+	fn to_quota_exceeded() -> TransactionValidity {
+		Err(TransactionValidityError::Invalid(
+			InvalidTransaction::Custom(DaInvalidTransactionCustomId::AppDataQuotaExceeded as u8),
+		))
+	}
+
 	#[test_case(1, submit_data_call() => Ok(ValidTransaction::default()); "Submit Data call should be allowed to use any valid AppId" )]
 	#[test_case(100, submit_data_call() => to_invalid_tx(InvalidAppId); "Submit Data call with invalid AppId should be blocked" )]
 	#[test_case(0, remark_call() => Ok(ValidTransaction::default()); "Any Non-Submit-Data call with AppId == 0 should be allowed" )]
 	#[test_case(1, remark_call() => to_invalid_tx(ForbiddenAppId); "Any Non-Submit-Data call with valid AppId != 0 should be blocked" )]
+	#[test_case(0, submit_data_batch_call(&[0, 1]) => Ok(ValidTransaction::default()); "Submit Data Batch call should be allowed to use any valid AppId per blob" )]
+	#[test_case(0, submit_data_batch_call(&[1, 100]) => to_invalid_tx(InvalidAppId); "Submit Data Batch call with an invalid blob AppId should be blocked" )]
+	#[test_case(1, submit_data_batch_call(&[1]) => to_invalid_tx(ForbiddenAppId); "Submit Data Batch call with AppId != 0 should be blocked" )]
 	fn do_validate_test(id: u32, call: RuntimeCall) -> TransactionValidity {
 		let extrinsic =
 			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
 		let len = extrinsic.encoded_size();
 		new_test_ext().execute_with(|| CheckAppId::<Test>::from(AppId(id)).do_validate(&call, len))
 	}
+
+	#[test_case(AppDataQuota { max_bytes: 1_000, max_scalars: 1_000 } => Ok(ValidTransaction::default()); "Submit Data call within the quota should be allowed" )]
+	#[test_case(AppDataQuota { max_bytes: 1, max_scalars: 1_000 } => to_quota_exceeded(); "Submit Data call over the bytes quota should be blocked" )]
+	#[test_case(AppDataQuota { max_bytes: 1_000, max_scalars: 0 } => to_quota_exceeded(); "Submit Data call over the scalars quota should be blocked" )]
+	fn app_data_quota_test(quota: AppDataQuota) -> TransactionValidity {
+		let call = submit_data_call();
+		let extrinsic =
+			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
+		let len = extrinsic.encoded_size();
+		new_test_ext().execute_with(|| {
+			crate::AppDataQuotas::<Test>::insert(AppId(1), quota);
+			CheckAppId::<Test>::from(AppId(1)).do_validate(&call, len)
+		})
+	}
+
+	#[test]
+	fn app_data_quota_is_per_app() {
+		let call = submit_data_call();
+		let extrinsic =
+			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
+		let len = extrinsic.encoded_size();
+		new_test_ext().execute_with(|| {
+			let quota = AppDataQuota {
+				max_bytes: len as u32,
+				max_scalars: 1_000,
+			};
+			crate::AppDataQuotas::<Test>::insert(AppId(1), quota);
+
+			let check = CheckAppId::<Test>::from(AppId(1));
+			assert_eq!(
+				check.do_validate(&call, len),
+				Ok(ValidTransaction::default())
+			);
+			assert_eq!(check.do_validate(&call, len), to_quota_exceeded());
+
+			let other = CheckAppId::<Test>::from(AppId(0));
+			assert_eq!(
+				other.do_validate(&call, len),
+				Ok(ValidTransaction::default())
+			);
+		})
+	}
+
+	#[test]
+	fn app_data_quota_counts_nested_submit_data() {
+		let call = RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![submit_data_call(), submit_data_call()],
+		});
+		let extrinsic =
+			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
+		let len = extrinsic.encoded_size();
+		new_test_ext().execute_with(|| {
+			let quota = AppDataQuota {
+				max_bytes: len as u32,
+				max_scalars: 1_000,
+			};
+			crate::AppDataQuotas::<Test>::insert(AppId(1), quota);
+
+			let check = CheckAppId::<Test>::from(AppId(1));
+			assert_eq!(
+				check.do_validate(&call, len),
+				Ok(ValidTransaction::default())
+			);
+			assert_eq!(check.do_validate(&call, len), to_quota_exceeded());
+		})
+	}
+
+	#[test]
+	fn nested_submit_data_without_quota_is_not_counted() {
+		let call = RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![submit_data_call()],
+		});
+		new_test_ext().execute_with(|| {
+			let check = CheckAppId::<Test>::from(AppId(1));
+			assert_eq!(
+				check.do_validate(&call, 100),
+				Ok(ValidTransaction::default())
+			);
+			assert!(AllExtrinsicsLen::<Test>::get().is_none());
+		})
+	}
+
+	#[test]
+	fn app_data_quota_is_per_blob() {
+		let call = submit_data_batch_call(&[0, 1]);
+		new_test_ext().execute_with(|| {
+			let quota = AppDataQuota {
+				max_bytes: 8,
+				max_scalars: 1_000,
+			};
+			crate::AppDataQuotas::<Test>::insert(AppId(1), quota);
+
+			let check = CheckAppId::<Test>::from(AppId(0));
+			assert_eq!(check.do_validate(&call, 0), Ok(ValidTransaction::default()));
+			assert_eq!(check.do_validate(&call, 0), to_quota_exceeded());
+		})
+	}
+
+	#[test]
+	fn retired_app_id_is_blocked() {
+		let call = submit_data_call();
+		let extrinsic =
+			AppUncheckedExtrinsic::<u32, RuntimeCall, (), ()>::new_unsigned(call.clone());
+		let len = extrinsic.encoded_size();
+		new_test_ext().execute_with(|| {
+			crate::RetiredAppIds::<Test>::insert(AppId(1), 1);
+			let validity = CheckAppId::<Test>::from(AppId(1)).do_validate(&call, len);
+			assert_eq!(validity, to_invalid_tx(InvalidAppId));
+		})
+	}
 }
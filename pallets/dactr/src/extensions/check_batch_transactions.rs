@@ -54,7 +54,10 @@ where
 	[u8; 32]: From<<T as frame_system::Config>::AccountId>,
 {
 	pub fn is_submit_data_call(&self) -> bool {
-		matches!(self.0.is_sub_type(), Some(DACall::<T>::submit_data { .. }))
+		matches!(
+			self.0.is_sub_type(),
+			Some(DACall::<T>::submit_data { .. }) | Some(DACall::<T>::submit_data_batch { .. })
+		)
 	}
 
 	pub fn is_send_message_call(&self) -> bool {
@@ -178,7 +181,7 @@ where
 		Self(sp_std::marker::PhantomData)
 	}
 
-	/// DataAvailability::submit_data and any Bridge::* transactions are forbidden to be included inside batch transactions.
+	/// DataAvailability::submit_data(_batch) and any Bridge::* transactions are forbidden to be included inside batch transactions.
 	pub fn do_validate(
 		&self,
 		call: &<T as SystemConfig>::RuntimeCall,
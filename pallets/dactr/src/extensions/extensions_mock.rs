@@ -4,18 +4,19 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::RuntimeDebug;
 use frame_support::parameter_types;
 use frame_support::traits::fungible::HoldConsideration;
+use frame_support::traits::AsEnsureOriginWithArg;
 use frame_support::traits::EqualPrivilegeOnly;
 use frame_support::traits::InstanceFilter;
 use frame_support::traits::LinearStoragePrice;
 use frame_support::weights::WeightToFee;
 use frame_support::{derive_impl, weights::IdentityFee};
-use frame_system::EnsureRoot;
 use frame_system::{
 	mocking::MockUncheckedExtrinsic, native::hosted_header_builder::da::HeaderExtensionBuilder,
 	test_utils::TestRandomness,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_transaction_payment::FungibleAdapter;
-use sp_core::ConstU32;
+use sp_core::{ConstU32, ConstU64};
 use sp_runtime::traits::BlakeTwo256;
 use sp_runtime::{AccountId32, BuildStorage};
 
@@ -33,6 +34,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Utility: pallet_utility,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		TransactionPayment: pallet_transaction_payment,
 		DataAvailability: da_control,
 		Vector: pallet_vector,
@@ -84,13 +86,48 @@ impl pallet_utility::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const AppKeyDeposit: Balance = 10;
+	pub const AppMetadataDepositPerByte: Balance = 1;
+	pub const MaxSponsoredFee: Balance = 1_000;
+}
+
 #[derive_impl(da_control::config_preludes::TestDefaultConfig)]
-impl da_control::Config for Test {}
+impl da_control::Config for Test {
+	type AppKeyDeposit = AppKeyDeposit;
+	type AppMetadataDepositPerByte = AppMetadataDepositPerByte;
+	type Currency = Balances;
+	type MaxSponsoredFee = MaxSponsoredFee;
+}
+
+impl pallet_assets::Config for Test {
+	type ApprovalDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Balance = u64;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type CallbackHandle = ();
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type Currency = Balances;
+	type Extra = ();
+	type ForceOrigin = EnsureRoot<AccountId32>;
+	type Freezer = ();
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = ConstU32<50>;
+	type WeightInfo = ();
+}
 
 #[derive_impl(pallet_vector::config_preludes::TestDefaultConfig as pallet_vector::DefaultConfig)]
 impl pallet_vector::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type Assets = Assets;
 }
 
 #[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig as pallet_timestamp::DefaultConfig)]
@@ -219,7 +256,7 @@ impl pallet_multisig::Config for Test {
 	type WeightInfo = ();
 }
 
-fn u8_to_account_id(value: u8) -> AccountId32 {
+pub fn u8_to_account_id(value: u8) -> AccountId32 {
 	let mut account = [0u8; 32];
 	account[0] = value;
 
@@ -1,5 +1,21 @@
+pub mod charge_app_transaction_payment;
 pub mod check_app_id;
 pub mod check_batch_transactions;
 pub mod extensions_mock;
 
 const MAX_ITERATIONS: usize = 5;
+
+/// Custom `InvalidTransaction` codes of `da_control` extensions which are not covered by
+/// `avail_core::InvalidTransactionCustomId`.
+///
+/// Values start at `200` to avoid clashing with the ones defined in `avail_core`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum DaInvalidTransactionCustomId {
+	/// The application exceeded its per-block data quota.
+	AppDataQuotaExceeded = 200,
+	/// A transaction paid by a sponsorship pot has a tip.
+	SponsoredTransactionTip = 201,
+	/// A `submit_data_batch` has blobs of applications with their own fee modifier.
+	BatchWithAppFeeModifier = 202,
+}
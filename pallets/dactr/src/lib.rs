@@ -6,29 +6,44 @@ use avail_core::{
 	AppId, BlockLengthColumns, BlockLengthRows, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO,
 	NORMAL_DISPATCH_RATIO,
 };
-use codec::{Compact, CompactLen as _};
+use codec::{Compact, CompactLen as _, Encode};
 use frame_support::weights::constants::ExtrinsicBaseWeight;
-use frame_support::{dispatch::DispatchClass, traits::Get, weights::Weight};
-use frame_system::{limits::BlockLength, pallet::DynamicBlockLength};
+use frame_support::{
+	dispatch::DispatchClass,
+	traits::{Currency, ExistenceRequirement, Get, ReservableCurrency},
+	weights::Weight,
+	PalletId,
+};
+use frame_system::{
+	limits::BlockLength,
+	pallet::{AllExtrinsicsLen, DynamicBlockLength},
+	pallet_prelude::BlockNumberFor,
+};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{CheckedAdd, One, SaturatedConversion};
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
-use sp_runtime::Perbill;
-use sp_std::{mem::replace, vec, vec::Vec};
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, Zero},
+	Perbill,
+};
+use sp_std::{boxed::Box, mem::replace, vec, vec::Vec};
 
 pub use crate::{pallet::*, weights::WeightInfo};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 mod extensions;
+pub mod migrations;
 #[cfg(feature = "std")]
 pub mod mock;
 #[cfg(test)]
 mod tests;
+pub use extensions::charge_app_transaction_payment::ChargeAppTransactionPayment;
 pub use extensions::check_app_id::CheckAppId;
 pub use extensions::check_batch_transactions::CheckBatchTransactions;
+pub use extensions::DaInvalidTransactionCustomId;
 use frame_support::dispatch::DispatchFeeModifier;
 pub mod weights;
 
@@ -37,9 +52,15 @@ pub const DA_DISPATCH_RATIO_PERBILL: Perbill = Perbill::from_percent(DA_DISPATCH
 pub const NORMAL_DISPATCH_RATIO_PERBILL: Perbill =
 	Perbill::from_percent(NORMAL_DISPATCH_RATIO as u32);
 
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::{pallet_prelude::*, DefaultNoBound};
+	use frame_support::{
+		pallet_prelude::*, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+		RuntimeDebugNoBound,
+	};
 	use frame_system::pallet_prelude::*;
 
 	use super::*;
@@ -49,14 +70,73 @@ pub mod pallet {
 
 	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, RuntimeDebug, MaxEncodedLen)]
-	pub struct AppKeyInfo<Acc: PartialEq> {
+	pub struct AppKeyInfo<Acc: PartialEq, Balance> {
 		/// Owner of the key
 		pub owner: Acc,
 		/// Application ID associated.
 		pub id: AppId,
+		/// Amount reserved from the owner for this key.
+		pub deposit: Balance,
 	}
 
-	pub type AppKeyInfoFor<T> = AppKeyInfo<<T as frame_system::Config>::AccountId>;
+	pub type AppKeyInfoFor<T> = AppKeyInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+	/// Per-block data limits of an application.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub struct AppDataQuota {
+		/// Maximum number of bytes submitted by the application in a block.
+		pub max_bytes: u32,
+		/// Maximum number of scalars used by the application in a block.
+		pub max_scalars: u32,
+	}
+
+	/// Expected encoding of the data submitted by an application.
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub enum AppDataEncoding {
+		Raw,
+		Blobstream,
+		Compressed,
+	}
+
+	/// Metadata of an application, set by the owner of its key.
+	#[derive(
+		CloneNoBound,
+		Encode,
+		Decode,
+		TypeInfo,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(S))]
+	#[codec(mel_bound())]
+	pub struct AppMetadata<S: Get<u32>> {
+		/// Name of the application, e.g. the rollup name.
+		pub name: BoundedVec<u8, S>,
+		/// Namespace of the application.
+		pub namespace: BoundedVec<u8, S>,
+		/// Website of the application.
+		pub website: BoundedVec<u8, S>,
+		/// Expected encoding of the submitted data.
+		pub encoding: AppDataEncoding,
+	}
+
+	pub type AppMetadataFor<T> = AppMetadata<<T as Config>::MaxAppMetadataLength>;
+
+	/// Automatic resizing of the grid rows based on its utilization.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug, MaxEncodedLen)]
+	pub struct GridAutoResizeConfig {
+		/// Target utilization of the grid, averaged over `GridUtilizationWindow` blocks.
+		pub target: Perbill,
+		/// Rows are multiplied or divided by `2^step` on each resize.
+		pub step: u32,
+	}
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
@@ -79,17 +159,23 @@ pub mod pallet {
 			pub const MaxBlockCols: BlockLengthColumns = BlockLengthColumns(1024);
 			pub const MaxAppKeyLength: u32 = 32;
 			pub const MaxAppDataLength: u32 = 1_048_576; // 1 Mb
+			pub const DaControlPalletId: PalletId = PalletId(*b"avl/dact");
+			pub const GridUtilizationWindow: u32 = 10;
+			pub const MaxAppMetadataLength: u32 = 64;
 		}
 
 		#[frame_support::register_default_impl(TestDefaultConfig)]
 		impl DefaultConfig for TestDefaultConfig {
 			type BlockLenProposalId = u32;
+			type GridUtilizationWindow = GridUtilizationWindow;
 			type MaxAppDataLength = MaxAppDataLength;
 			type MaxAppKeyLength = MaxAppKeyLength;
+			type MaxAppMetadataLength = MaxAppMetadataLength;
 			type MaxBlockCols = MaxBlockCols;
 			type MaxBlockRows = MaxBlockRows;
 			type MinBlockCols = MinBlockCols;
 			type MinBlockRows = MinBlockRows;
+			type PalletId = DaControlPalletId;
 			type WeightInfo = ();
 			#[inject_runtime_type]
 			type RuntimeEvent = ();
@@ -131,9 +217,41 @@ pub mod pallet {
 
 		/// Weights for this pallet.
 		type WeightInfo: weights::WeightInfo;
+
+		/// Currency used to reserve the deposit of application keys.
+		#[pallet::no_default]
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved from the owner when an application key is created.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type AppKeyDeposit: Get<BalanceOf<Self>>;
+
+		/// Pallet id, used for deriving the sponsorship pots of applications.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Number of blocks used to average the grid utilization for its automatic resizing.
+		#[pallet::constant]
+		type GridUtilizationWindow: Get<u32>;
+
+		/// The max length of each field of the application metadata.
+		#[pallet::constant]
+		type MaxAppMetadataLength: Get<u32>;
+
+		/// The amount reserved from the owner per byte of application metadata.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type AppMetadataDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The maximum fee paid by a sponsorship pot for a single transaction.
+		#[pallet::constant]
+		#[pallet::no_default]
+		type MaxSponsoredFee: Get<BalanceOf<Self>>;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Last application ID
@@ -146,13 +264,62 @@ pub mod pallet {
 	#[pallet::getter(fn application_key)]
 	pub type AppKeys<T: Config> = StorageMap<_, Blake2_128Concat, AppKeyFor<T>, AppKeyInfoFor<T>>;
 
+	/// Block length proposal waiting for its enactment, and the block where it is enacted.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_block_length)]
+	pub type PendingBlockLength<T: Config> = StorageValue<_, (BlockNumberFor<T>, BlockLength)>;
+
+	/// Configuration of the automatic grid resizing. It is disabled if it is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn grid_auto_resize)]
+	pub type GridAutoResize<T: Config> = StorageValue<_, GridAutoResizeConfig>;
+
+	/// Grid utilization of the last blocks, used by the automatic grid resizing.
+	#[pallet::storage]
+	pub type GridUtilization<T: Config> =
+		StorageValue<_, BoundedVec<Perbill, T::GridUtilizationWindow>, ValueQuery>;
+
+	/// Metadata of applications, and the amount reserved from their owners for it.
+	#[pallet::storage]
+	#[pallet::getter(fn app_metadata)]
+	pub type AppMetadataOf<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, (AppMetadataFor<T>, BalanceOf<T>)>;
+
+	/// Reverse index of `AppKeys`: the application key of each application ID.
+	#[pallet::storage]
+	pub type AppIdToKey<T: Config> = StorageMap<_, Twox64Concat, AppId, AppKeyFor<T>>;
+
 	/// Store data fee modifier for submit_data call.
 	#[pallet::storage]
 	pub type SubmitDataFeeModifier<T: Config> = StorageValue<_, DispatchFeeModifier, ValueQuery>;
 
+	/// Store data fee modifiers for submit_data call of specific applications.
+	/// Applications without a modifier use `SubmitDataFeeModifier`.
+	#[pallet::storage]
+	pub type SubmitDataFeeModifierByApp<T: Config> =
+		StorageMap<_, Twox64Concat, AppId, DispatchFeeModifier>;
+
+	/// Application IDs retired by their owners, and the block where they were retired.
+	#[pallet::storage]
+	pub type RetiredAppIds<T: Config> = StorageMap<_, Twox64Concat, AppId, BlockNumberFor<T>>;
+
+	/// Per-block data quotas of applications. Applications without quota are only limited by
+	/// the block length.
+	#[pallet::storage]
+	#[pallet::getter(fn app_data_quota)]
+	pub type AppDataQuotas<T: Config> = StorageMap<_, Twox64Concat, AppId, AppDataQuota>;
+
+	/// Remaining number of `submit_data` transactions of a signer paid by the sponsorship pot
+	/// of an application.
+	#[pallet::storage]
+	pub type SponsoredAllowances<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, AppId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates an application key if `key` does not exist yet.
+		///
+		/// `AppKeyDeposit` is reserved from the owner until the key is retired or transferred.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::create_application_key())]
 		pub fn create_application_key(
@@ -161,17 +328,22 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let owner = ensure_signed(origin)?;
 			ensure!(!key.is_empty(), Error::<T>::AppKeyCannotBeEmpty);
-			let id = AppKeys::<T>::try_mutate(&key, |key_info| -> Result<AppId, Error<T>> {
+			let id = AppKeys::<T>::try_mutate(&key, |key_info| -> Result<AppId, DispatchError> {
 				ensure!(key_info.is_none(), Error::<T>::AppKeyAlreadyExists);
 
+				let deposit = T::AppKeyDeposit::get();
+				T::Currency::reserve(&owner, deposit)?;
+
 				let id = Self::next_application_id()?;
 				*key_info = Some(AppKeyInfo {
 					id,
 					owner: owner.clone(),
+					deposit,
 				});
 
 				Ok(id)
 			})?;
+			AppIdToKey::<T>::insert(id, &key);
 
 			Self::deposit_event(Event::ApplicationKeyCreated { key, owner, id });
 			Ok(().into())
@@ -209,26 +381,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let rows = BlockLengthRows(rows);
 			let cols = BlockLengthColumns(cols);
-
-			ensure!(
-				rows <= T::MaxBlockRows::get() && cols <= T::MaxBlockCols::get(),
-				Error::<T>::BlockDimensionsOutOfBounds
-			);
-			ensure!(
-				rows >= T::MinBlockRows::get() && cols >= T::MinBlockCols::get(),
-				Error::<T>::BlockDimensionsTooSmall
-			);
-
-			// Check if rows and cols are powers of 2
-			// Check if `rows` or `cols` are a power of 2: they must be nonzero and have no bits in common with `(rows or cols) - 1`.
-			ensure!(
-				rows.0 != 0 && (rows.0 & (rows.0 - 1)) == 0,
-				Error::<T>::NotPowerOfTwo
-			);
-			ensure!(
-				cols.0 != 0 && (cols.0 & (cols.0 - 1)) == 0,
-				Error::<T>::NotPowerOfTwo
-			);
+			let block_length = Self::proposed_block_length(rows, cols)?;
 
 			let current_block_dimension = DynamicBlockLength::<T>::get();
 			let is_increase =
@@ -238,14 +391,6 @@ pub mod pallet {
 				Error::<T>::InvalidBlockWeightReduction
 			);
 
-			let block_length = BlockLength::with_normal_ratio(
-				rows,
-				cols,
-				BLOCK_CHUNK_SIZE,
-				DA_DISPATCH_RATIO_PERBILL,
-			)
-			.map_err(|_| Error::<T>::BlockDimensionsOutOfBounds)?;
-
 			DynamicBlockLength::<T>::put(block_length);
 
 			Self::deposit_event(Event::BlockLengthProposalSubmitted { rows, cols });
@@ -279,6 +424,7 @@ pub mod pallet {
 			AppKeys::<T>::remove(&old_key);
 
 			// Insert the app info under the new key
+			AppIdToKey::<T>::insert(app_key_info.id, &new_key);
 			AppKeys::<T>::insert(&new_key, app_key_info);
 
 			Self::deposit_event(Event::ApplicationKeySet { old_key, new_key });
@@ -300,6 +446,379 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Transfers the ownership of the application `key` to `new_owner`.
+		///
+		/// The deposit of the old owner is unreserved, and `AppKeyDeposit` is reserved from
+		/// `new_owner`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::transfer_application_key())]
+		pub fn transfer_application_key(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let old_owner = ensure_signed(origin)?;
+
+			let id = AppKeys::<T>::try_mutate(&key, |key_info| -> Result<AppId, DispatchError> {
+				let key_info = key_info.as_mut().ok_or(Error::<T>::UnknownAppKey)?;
+				ensure!(key_info.owner == old_owner, Error::<T>::NotAppKeyOwner);
+
+				let deposit = T::AppKeyDeposit::get();
+				T::Currency::reserve(&new_owner, deposit)?;
+				T::Currency::unreserve(&old_owner, key_info.deposit);
+
+				if let Some((_, metadata_deposit)) = AppMetadataOf::<T>::get(key_info.id) {
+					T::Currency::reserve(&new_owner, metadata_deposit)?;
+					T::Currency::unreserve(&old_owner, metadata_deposit);
+				}
+
+				key_info.owner = new_owner.clone();
+				key_info.deposit = deposit;
+				Ok(key_info.id)
+			})?;
+
+			Self::deposit_event(Event::ApplicationKeyTransferred {
+				key,
+				id,
+				old_owner,
+				new_owner,
+			});
+
+			Ok(().into())
+		}
+
+		/// Retires the application `key`.
+		///
+		/// The key and its metadata are removed, their deposits and the sponsorship pot are
+		/// returned to the owner, and its
+		/// application ID cannot be used to submit data anymore.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::retire_application_key())]
+		pub fn retire_application_key(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+
+			let key_info = AppKeys::<T>::get(&key).ok_or(Error::<T>::UnknownAppKey)?;
+			ensure!(key_info.owner == owner, Error::<T>::NotAppKeyOwner);
+
+			let pot = Self::sponsor_pot_account(key_info.id);
+			let pot_balance = T::Currency::free_balance(&pot);
+			if !pot_balance.is_zero() {
+				T::Currency::transfer(&pot, &owner, pot_balance, ExistenceRequirement::AllowDeath)?;
+			}
+
+			AppKeys::<T>::remove(&key);
+			AppIdToKey::<T>::remove(key_info.id);
+			T::Currency::unreserve(&owner, key_info.deposit);
+			if let Some((_, metadata_deposit)) = AppMetadataOf::<T>::take(key_info.id) {
+				T::Currency::unreserve(&owner, metadata_deposit);
+			}
+			let now = <frame_system::Pallet<T>>::block_number();
+			RetiredAppIds::<T>::insert(key_info.id, now);
+
+			Self::deposit_event(Event::ApplicationKeyRetired {
+				key,
+				id: key_info.id,
+				owner,
+			});
+
+			Ok(().into())
+		}
+
+		/// Sets the per-block data `quota` of the application `id`, or removes it if `None`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_app_data_quota())]
+		pub fn set_app_data_quota(
+			origin: OriginFor<T>,
+			id: AppId,
+			quota: Option<AppDataQuota>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			AppDataQuotas::<T>::set(id, quota);
+
+			Self::deposit_event(Event::AppDataQuotaSet { id, quota });
+
+			Ok(().into())
+		}
+
+		/// Sets the fee `modifier` of `submit_data` for the application `id`, or removes it if
+		/// `None`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_submit_data_fee_modifier_by_app())]
+		pub fn set_submit_data_fee_modifier_by_app(
+			origin: OriginFor<T>,
+			id: AppId,
+			modifier: Option<DispatchFeeModifier>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			SubmitDataFeeModifierByApp::<T>::set(id, modifier);
+
+			Self::deposit_event(Event::SubmitDataFeeModifierByAppSet {
+				id,
+				value: modifier,
+			});
+
+			Ok(().into())
+		}
+
+		/// Transfers `amount` from the owner of the application `key` to its sponsorship pot.
+		///
+		/// The pot pays the `submit_data` fees of signers with a sponsored allowance.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::fund_sponsorship())]
+		pub fn fund_sponsorship(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let id = Self::app_id_owned_by(&key, &owner)?;
+
+			let pot = Self::sponsor_pot_account(id);
+			T::Currency::transfer(&owner, &pot, amount, ExistenceRequirement::KeepAlive)?;
+
+			Self::deposit_event(Event::SponsorshipFunded { id, owner, amount });
+
+			Ok(().into())
+		}
+
+		/// Transfers `amount` from the sponsorship pot of the application `key` back to its owner.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::withdraw_sponsorship())]
+		pub fn withdraw_sponsorship(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let id = Self::app_id_owned_by(&key, &owner)?;
+
+			let pot = Self::sponsor_pot_account(id);
+			T::Currency::transfer(&pot, &owner, amount, ExistenceRequirement::AllowDeath)?;
+
+			Self::deposit_event(Event::SponsorshipWithdrawn { id, owner, amount });
+
+			Ok(().into())
+		}
+
+		/// Allows the sponsorship pot of the application `key` to pay the fees of the next
+		/// `allowance` `submit_data` transactions of `signer`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_sponsored_allowance())]
+		pub fn set_sponsored_allowance(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			signer: T::AccountId,
+			allowance: u32,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let id = Self::app_id_owned_by(&key, &owner)?;
+
+			SponsoredAllowances::<T>::insert(id, &signer, allowance);
+
+			Self::deposit_event(Event::SponsoredAllowanceSet {
+				id,
+				signer,
+				allowance,
+			});
+
+			Ok(().into())
+		}
+
+		/// Revokes the sponsored allowance of `signer` on the application `key`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::revoke_sponsored_allowance())]
+		pub fn revoke_sponsored_allowance(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			signer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let id = Self::app_id_owned_by(&key, &owner)?;
+
+			ensure!(
+				SponsoredAllowances::<T>::contains_key(id, &signer),
+				Error::<T>::UnknownSponsoredSigner
+			);
+			SponsoredAllowances::<T>::remove(id, &signer);
+
+			Self::deposit_event(Event::SponsoredAllowanceRevoked { id, signer });
+
+			Ok(().into())
+		}
+
+		/// Submits several blobs in one transaction, each of them using its own application ID.
+		///
+		/// The transaction itself must use `AppId(0)`. Each blob becomes its own data root leaf.
+		/// The batch is charged using `SubmitDataFeeModifier`, so transactions with blobs of
+		/// applications having their own fee modifier (see `SubmitDataFeeModifierByApp`) are
+		/// rejected.
+		#[pallet::call_index(13)]
+		#[pallet::weight((
+			weight_helper::submit_data_batch::<T>(blobs),
+			DispatchClass::Normal,
+			SubmitDataFeeModifier::<T>::get()
+		))]
+		pub fn submit_data_batch(
+			origin: OriginFor<T>,
+			blobs: Vec<(AppId, AppDataFor<T>)>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!blobs.is_empty(), Error::<T>::DataCannotBeEmpty);
+			ensure!(
+				blobs.iter().all(|(_, data)| !data.is_empty()),
+				Error::<T>::DataCannotBeEmpty
+			);
+
+			for (_, data) in blobs.iter() {
+				let data_hash = blake2_256(data);
+				Self::deposit_event(Event::DataSubmitted {
+					who: who.clone(),
+					data_hash: H256(data_hash),
+				});
+			}
+
+			Ok(().into())
+		}
+
+		/// Schedules a block length proposal, which is enacted at the beginning of block `when`.
+		///
+		/// Only one proposal can be pending, and it can be cancelled using
+		/// `cancel_block_length_proposal` before its enactment.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::schedule_block_length_proposal())]
+		pub fn schedule_block_length_proposal(
+			origin: OriginFor<T>,
+			rows: u32,
+			cols: u32,
+			when: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(
+				when > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::EnactmentBlockInThePast
+			);
+			ensure!(
+				!PendingBlockLength::<T>::exists(),
+				Error::<T>::BlockLengthProposalAlreadyPending
+			);
+
+			let rows = BlockLengthRows(rows);
+			let cols = BlockLengthColumns(cols);
+			let block_length = Self::proposed_block_length(rows, cols)?;
+			PendingBlockLength::<T>::put((when, block_length));
+
+			Self::deposit_event(Event::BlockLengthProposalScheduled { rows, cols, when });
+
+			Ok(().into())
+		}
+
+		/// Cancels the pending block length proposal.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::cancel_block_length_proposal())]
+		pub fn cancel_block_length_proposal(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let (when, block_length) =
+				PendingBlockLength::<T>::take().ok_or(Error::<T>::NoPendingBlockLengthProposal)?;
+
+			Self::deposit_event(Event::BlockLengthProposalCancelled {
+				rows: block_length.rows,
+				cols: block_length.cols,
+				when,
+			});
+
+			Ok(().into())
+		}
+
+		/// Enables, updates or disables (using `None`) the automatic grid resizing.
+		///
+		/// Rows are resized within `MinBlockRows..=MaxBlockRows` to keep the average grid
+		/// utilization around `config.target`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_grid_auto_resize())]
+		pub fn set_grid_auto_resize(
+			origin: OriginFor<T>,
+			config: Option<GridAutoResizeConfig>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			if let Some(config) = config {
+				ensure!(
+					!config.target.is_zero() && (1..32).contains(&config.step),
+					Error::<T>::InvalidGridAutoResizeConfig
+				);
+			}
+
+			GridAutoResize::<T>::set(config);
+			GridUtilization::<T>::kill();
+
+			Self::deposit_event(Event::GridAutoResizeSet { config });
+
+			Ok(().into())
+		}
+
+		/// Sets or updates the metadata of the application `key`.
+		///
+		/// A deposit proportional to the encoded size of `metadata` is reserved from the owner.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_app_metadata())]
+		pub fn set_app_metadata(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+			metadata: Box<AppMetadataFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let id = Self::app_id_owned_by(&key, &owner)?;
+
+			let deposit = Self::app_metadata_deposit(&metadata);
+			let old_deposit = AppMetadataOf::<T>::get(id)
+				.map(|(_, deposit)| deposit)
+				.unwrap_or_else(Zero::zero);
+			if deposit > old_deposit {
+				T::Currency::reserve(&owner, deposit.saturating_sub(old_deposit))?;
+			} else {
+				T::Currency::unreserve(&owner, old_deposit.saturating_sub(deposit));
+			}
+			AppMetadataOf::<T>::insert(id, (*metadata, deposit));
+
+			Self::deposit_event(Event::AppMetadataSet { id, deposit });
+
+			Ok(().into())
+		}
+
+		/// Removes the metadata of the application `key`, and returns its deposit to the owner.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::clear_app_metadata())]
+		pub fn clear_app_metadata(
+			origin: OriginFor<T>,
+			key: AppKeyFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let id = Self::app_id_owned_by(&key, &owner)?;
+
+			let (_, deposit) =
+				AppMetadataOf::<T>::take(id).ok_or(Error::<T>::UnknownAppMetadata)?;
+			T::Currency::unreserve(&owner, deposit);
+
+			Self::deposit_event(Event::AppMetadataCleared { id });
+
+			Ok(().into())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::enact_pending_block_length(now).saturating_add(Self::auto_resize_block_length())
+		}
+
+		fn on_finalize(_now: BlockNumberFor<T>) {
+			Self::record_grid_utilization();
+		}
 	}
 
 	/// Event for the pallet.
@@ -327,6 +846,93 @@ pub mod pallet {
 		SubmitDataFeeModifierSet {
 			value: DispatchFeeModifier,
 		},
+		/// An application key was transferred to a new owner.
+		ApplicationKeyTransferred {
+			key: AppKeyFor<T>,
+			id: AppId,
+			old_owner: T::AccountId,
+			new_owner: T::AccountId,
+		},
+		/// An application key was retired by its owner.
+		ApplicationKeyRetired {
+			key: AppKeyFor<T>,
+			id: AppId,
+			owner: T::AccountId,
+		},
+		/// The per-block data quota of an application was set or removed.
+		AppDataQuotaSet {
+			id: AppId,
+			quota: Option<AppDataQuota>,
+		},
+		/// The submit data fee modifier of an application was set or removed.
+		SubmitDataFeeModifierByAppSet {
+			id: AppId,
+			value: Option<DispatchFeeModifier>,
+		},
+		/// The sponsorship pot of an application was funded by its owner.
+		SponsorshipFunded {
+			id: AppId,
+			owner: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Funds were withdrawn from the sponsorship pot of an application by its owner.
+		SponsorshipWithdrawn {
+			id: AppId,
+			owner: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// The sponsored allowance of a signer was set.
+		SponsoredAllowanceSet {
+			id: AppId,
+			signer: T::AccountId,
+			allowance: u32,
+		},
+		/// The sponsored allowance of a signer was revoked.
+		SponsoredAllowanceRevoked {
+			id: AppId,
+			signer: T::AccountId,
+		},
+		/// A block length proposal was scheduled to be enacted at block `when`.
+		BlockLengthProposalScheduled {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+			when: BlockNumberFor<T>,
+		},
+		/// The pending block length proposal was cancelled.
+		BlockLengthProposalCancelled {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+			when: BlockNumberFor<T>,
+		},
+		/// The pending block length proposal was enacted.
+		BlockLengthProposalEnacted {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+		},
+		/// The pending block length proposal was dropped, as it reduces the block length while
+		/// the block weight is too high.
+		BlockLengthProposalFailed {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+		},
+		/// The automatic grid resizing was enabled, updated or disabled.
+		GridAutoResizeSet {
+			config: Option<GridAutoResizeConfig>,
+		},
+		/// The block length was automatically resized based on the grid utilization.
+		BlockLengthAutoResized {
+			rows: BlockLengthRows,
+			cols: BlockLengthColumns,
+		},
+		/// The metadata of an application was set or updated.
+		AppMetadataSet {
+			id: AppId,
+			deposit: BalanceOf<T>,
+		},
+		/// The metadata of an application was removed.
+		AppMetadataCleared {
+			id: AppId,
+		},
 	}
 
 	/// Error for the System pallet
@@ -354,6 +960,20 @@ pub mod pallet {
 		UnknownAppKey,
 		/// Submit block length proposal was made with values not power of 2
 		NotPowerOfTwo,
+		/// The origin is not the owner of the application key
+		NotAppKeyOwner,
+		/// The signer has no sponsored allowance for the application
+		UnknownSponsoredSigner,
+		/// The enactment block of a block length proposal is not in the future
+		EnactmentBlockInThePast,
+		/// Another block length proposal is already pending
+		BlockLengthProposalAlreadyPending,
+		/// There is no pending block length proposal
+		NoPendingBlockLengthProposal,
+		/// The target of the automatic grid resizing is zero or its step is out of bounds
+		InvalidGridAutoResizeConfig,
+		/// The application has no metadata
+		UnknownAppMetadata,
 	}
 
 	#[pallet::genesis_config]
@@ -380,10 +1000,13 @@ pub mod pallet {
 			for (key, (owner, id)) in app_keys {
 				let key = AppKeyFor::<T>::try_from(key)
 					.expect("DA Control Genesis contains invalid keys");
+				// Genesis keys do not hold any deposit.
 				let value = AppKeyInfo {
 					id: AppId(id),
 					owner,
+					deposit: Zero::zero(),
 				};
+				AppIdToKey::<T>::insert(AppId(id), &key);
 				AppKeys::<T>::insert(key, value);
 			}
 		}
@@ -399,6 +1022,219 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Returns the application ID of `key` if it is owned by `who`.
+	fn app_id_owned_by(key: &AppKeyFor<T>, who: &T::AccountId) -> Result<AppId, Error<T>> {
+		let key_info = AppKeys::<T>::get(key).ok_or(Error::<T>::UnknownAppKey)?;
+		frame_support::ensure!(key_info.owner == *who, Error::<T>::NotAppKeyOwner);
+		Ok(key_info.id)
+	}
+
+	/// The account ID of the sponsorship pot of the application `id`.
+	pub fn sponsor_pot_account(id: AppId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(id)
+	}
+
+	/// Returns the sponsorship pot paying the `submit_data` fees of `who` on the application
+	/// `id`, if `who` has any sponsored allowance left.
+	pub fn sponsor_of(id: AppId, who: &T::AccountId) -> Option<T::AccountId> {
+		let allowance = SponsoredAllowances::<T>::get(id, who);
+		(allowance > 0).then(|| Self::sponsor_pot_account(id))
+	}
+
+	/// Consumes one sponsored `submit_data` transaction of `who` on the application `id`.
+	pub fn consume_sponsored_allowance(id: AppId, who: &T::AccountId) {
+		SponsoredAllowances::<T>::mutate_exists(id, who, |allowance| {
+			*allowance = allowance
+				.and_then(|allowance| allowance.checked_sub(1))
+				.filter(|allowance| *allowance > 0);
+		});
+	}
+
+	/// Builds the `BlockLength` of a block length proposal, checking its dimensions.
+	fn proposed_block_length(
+		rows: BlockLengthRows,
+		cols: BlockLengthColumns,
+	) -> Result<BlockLength, Error<T>> {
+		frame_support::ensure!(
+			rows <= T::MaxBlockRows::get() && cols <= T::MaxBlockCols::get(),
+			Error::<T>::BlockDimensionsOutOfBounds
+		);
+		frame_support::ensure!(
+			rows >= T::MinBlockRows::get() && cols >= T::MinBlockCols::get(),
+			Error::<T>::BlockDimensionsTooSmall
+		);
+
+		// Check if rows and cols are powers of 2
+		// Check if `rows` or `cols` are a power of 2: they must be nonzero and have no bits in common with `(rows or cols) - 1`.
+		frame_support::ensure!(
+			rows.0 != 0 && (rows.0 & (rows.0 - 1)) == 0,
+			Error::<T>::NotPowerOfTwo
+		);
+		frame_support::ensure!(
+			cols.0 != 0 && (cols.0 & (cols.0 - 1)) == 0,
+			Error::<T>::NotPowerOfTwo
+		);
+
+		BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO_PERBILL)
+			.map_err(|_| Error::<T>::BlockDimensionsOutOfBounds)
+	}
+
+	/// Enacts the pending block length proposal if its enactment block is `now` or earlier.
+	fn enact_pending_block_length(now: BlockNumberFor<T>) -> Weight {
+		let Some((when, block_length)) = PendingBlockLength::<T>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+		if now < when {
+			return T::DbWeight::get().reads(1);
+		}
+
+		PendingBlockLength::<T>::kill();
+		let (rows, cols) = (block_length.rows, block_length.cols);
+		// Like `submit_block_length_proposal`, reductions need an acceptable block weight.
+		let current = DynamicBlockLength::<T>::get();
+		let is_increase = rows >= current.rows && cols >= current.cols;
+		if !is_increase && !Self::is_block_weight_acceptable() {
+			Self::deposit_event(Event::BlockLengthProposalFailed { rows, cols });
+			return T::WeightInfo::enact_pending_block_length();
+		}
+
+		DynamicBlockLength::<T>::put(block_length);
+		Self::deposit_event(Event::BlockLengthProposalEnacted { rows, cols });
+
+		T::WeightInfo::enact_pending_block_length()
+	}
+
+	/// Resizes the grid rows if the average utilization of the last `GridUtilizationWindow`
+	/// blocks is far from the target of `GridAutoResize`.
+	///
+	/// Rows grow if the average utilization is over the target, and they shrink if the
+	/// utilization would still be under the target after shrinking them.
+	///
+	/// The returned weight includes `record_grid_utilization`, executed on `on_finalize`.
+	fn auto_resize_block_length() -> Weight {
+		let Some(config) = GridAutoResize::<T>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+
+		let samples = GridUtilization::<T>::get();
+		let weight = T::WeightInfo::auto_resize_block_length(samples.len().saturated_into());
+		let window = T::GridUtilizationWindow::get();
+		if samples.is_empty() || samples.len() < window as usize {
+			return weight;
+		}
+		let num_samples = samples.len() as u64;
+		let total = samples
+			.iter()
+			.map(|utilization| u64::from(utilization.deconstruct()))
+			.sum::<u64>();
+		let average = Perbill::from_parts((total / num_samples).saturated_into());
+
+		let current = DynamicBlockLength::<T>::get();
+		let Some(rows) = Self::resized_rows(current.rows, average, &config) else {
+			return weight;
+		};
+		let cols = current.cols;
+		let Ok(block_length) =
+			BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO_PERBILL)
+		else {
+			return weight;
+		};
+
+		DynamicBlockLength::<T>::put(block_length);
+		GridUtilization::<T>::kill();
+		Self::deposit_event(Event::BlockLengthAutoResized { rows, cols });
+
+		weight
+	}
+
+	/// Returns the new rows for `average` utilization, or `None` if `rows` should be kept.
+	fn resized_rows(
+		rows: BlockLengthRows,
+		average: Perbill,
+		config: &GridAutoResizeConfig,
+	) -> Option<BlockLengthRows> {
+		let factor = 1u32.checked_shl(config.step)?;
+		let average = average.deconstruct();
+		let target = config.target.deconstruct();
+
+		let next_rows = if average > target {
+			rows.0.saturating_mul(factor).min(T::MaxBlockRows::get().0)
+		} else if average.saturating_mul(factor) < target {
+			(rows.0 / factor).max(T::MinBlockRows::get().0)
+		} else {
+			rows.0
+		};
+
+		(next_rows != rows.0).then_some(BlockLengthRows(next_rows))
+	}
+
+	/// Records the grid utilization of the current block, if the automatic grid resizing is
+	/// enabled.
+	fn record_grid_utilization() {
+		if !GridAutoResize::<T>::exists() {
+			return;
+		}
+
+		let block_length = DynamicBlockLength::<T>::get();
+		let max_scalars = block_length.rows.0.saturating_mul(block_length.cols.0);
+		let used_scalars = AllExtrinsicsLen::<T>::get()
+			.and_then(|len| len.total_num_scalars())
+			.unwrap_or_default();
+		let utilization = Perbill::from_rational(used_scalars, max_scalars);
+
+		GridUtilization::<T>::mutate(|samples| {
+			if samples.len() >= T::GridUtilizationWindow::get() as usize && !samples.is_empty() {
+				samples.remove(0);
+			}
+			let _ = samples.try_push(utilization);
+		});
+	}
+
+	/// The amount reserved for `metadata`, proportional to its encoded size.
+	fn app_metadata_deposit(metadata: &AppMetadataFor<T>) -> BalanceOf<T> {
+		let len: u32 = metadata.encoded_size().saturated_into();
+		T::AppMetadataDepositPerByte::get().saturating_mul(len.into())
+	}
+
+	/// Returns up to `limit` application keys with their info and metadata, starting after
+	/// `start_after`.
+	#[allow(clippy::type_complexity)]
+	pub fn apps(
+		start_after: Option<AppKeyFor<T>>,
+		limit: u32,
+	) -> Vec<(AppKeyFor<T>, AppKeyInfoFor<T>, Option<AppMetadataFor<T>>)> {
+		let keys = match start_after {
+			Some(key) => AppKeys::<T>::iter_from(AppKeys::<T>::hashed_key_for(key)),
+			None => AppKeys::<T>::iter(),
+		};
+
+		keys.take(limit as usize)
+			.map(|(key, info)| {
+				let metadata = AppMetadataOf::<T>::get(info.id).map(|(metadata, _)| metadata);
+				(key, info, metadata)
+			})
+			.collect()
+	}
+
+	/// Returns the application key of `id` and its info.
+	pub fn app_key_by_id(id: AppId) -> Option<(AppKeyFor<T>, AppKeyInfoFor<T>)> {
+		let key = AppIdToKey::<T>::get(id)?;
+		let info = AppKeys::<T>::get(&key)?;
+		Some((key, info))
+	}
+
+	/// Returns the application keys owned by `owner` and their info.
+	pub fn app_keys_by_owner(owner: &T::AccountId) -> Vec<(AppKeyFor<T>, AppKeyInfoFor<T>)> {
+		AppKeys::<T>::iter()
+			.filter(|(_, info)| info.owner == *owner)
+			.collect()
+	}
+
+	/// Returns `true` if the application `id` was retired by its owner.
+	pub fn is_app_id_retired(id: AppId) -> bool {
+		RetiredAppIds::<T>::contains_key(id)
+	}
+
 	/// Check if the block weight is acceptable to execute the extrinsic
 	/// We check the current normal ratio weight, if it's too high, it means we won't reduce the block size
 	pub fn is_block_weight_acceptable() -> bool {
@@ -462,17 +1298,32 @@ pub mod weight_helper {
 		scalar_based_weight.max(regular_weight)
 	}
 
+	/// Weight for `dataAvailability::submit_data_batch`, i.e. the weight of one
+	/// `dataAvailability::submit_data` per blob.
+	pub fn submit_data_batch<T: Config>(blobs: &[(AppId, AppDataFor<T>)]) -> Weight {
+		blobs.iter().fold(Weight::zero(), |acc, (_, data)| {
+			acc.saturating_add(submit_data::<T>(data.len()))
+		})
+	}
+
+	/// Fee modifier for `dataAvailability::submit_data` using `app_id`.
+	///
+	/// It falls back to `SubmitDataFeeModifier` if the application has no modifier.
+	pub fn submit_data_fee_modifier<T: Config>(app_id: AppId) -> DispatchFeeModifier {
+		SubmitDataFeeModifierByApp::<T>::get(app_id).unwrap_or_else(SubmitDataFeeModifier::<T>::get)
+	}
+
 	fn compact_len(value: &u32) -> Option<u32> {
 		let len = Compact::<u32>::compact_len(value);
 		u32::try_from(len).ok()
 	}
 }
 
-impl<Acc> AppKeyInfo<Acc>
+impl<Acc, Balance> AppKeyInfo<Acc, Balance>
 where
 	Acc: PartialEq,
 {
-	pub fn new(owner: Acc, id: AppId) -> Self {
-		Self { owner, id }
+	pub fn new(owner: Acc, id: AppId, deposit: Balance) -> Self {
+		Self { owner, id, deposit }
 	}
 }
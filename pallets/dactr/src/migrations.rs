@@ -0,0 +1,171 @@
+use crate::{AppKeyInfo, AppKeys, BalanceOf, Config, Pallet, LOG_TARGET};
+use avail_core::AppId;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::{
+		Currency, Get, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+	},
+	weights::Weight,
+};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use {sp_runtime::TryRuntimeError, sp_std::vec::Vec};
+
+/// Adds the reserved `deposit` to `AppKeyInfo`.
+///
+/// The genesis application keys, with an id below `GenesisAppIds`, are grandfathered: they are
+/// migrated without any deposit. `AppKeyDeposit` is reserved from the owners of the keys created
+/// later, as far as their free balance allows. The `deposit` of each key records what was actually
+/// reserved, and the shortfall is logged.
+pub mod v1 {
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	struct OldAppKeyInfo<Acc> {
+		owner: Acc,
+		id: AppId,
+	}
+
+	pub struct MigrateToV1<T, GenesisAppIds>(PhantomData<(T, GenesisAppIds)>);
+
+	/// Reserves up to `deposit` from `owner`, keeping the existential deposit free.
+	fn reserve_best_effort<T: Config>(owner: &T::AccountId, deposit: BalanceOf<T>) -> BalanceOf<T> {
+		let available = T::Currency::free_balance(owner)
+			.saturating_sub(T::Currency::minimum_balance())
+			.min(deposit);
+		match T::Currency::reserve(owner, available) {
+			Ok(()) => available,
+			Err(_) => Zero::zero(),
+		}
+	}
+
+	impl<T: Config, GenesisAppIds: Get<u32>> OnRuntimeUpgrade for MigrateToV1<T, GenesisAppIds> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to v1, on-chain storage version is {on_chain_version:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			let mut reserved = 0u64;
+			let mut shortfall = BalanceOf::<T>::zero();
+			AppKeys::<T>::translate::<OldAppKeyInfo<T::AccountId>, _>(|_key, old| {
+				translated = translated.saturating_add(1);
+				let deposit = if old.id.0 < GenesisAppIds::get() {
+					Zero::zero()
+				} else {
+					reserved = reserved.saturating_add(1);
+					let expected = T::AppKeyDeposit::get();
+					let deposit = reserve_best_effort::<T>(&old.owner, expected);
+					if deposit < expected {
+						log::warn!(
+							target: LOG_TARGET,
+							"Application key {:?} holds a deposit of {deposit:?} instead of {expected:?}",
+							old.id
+						);
+						shortfall = shortfall.saturating_add(expected.saturating_sub(deposit));
+					}
+					deposit
+				};
+				Some(AppKeyInfo {
+					owner: old.owner,
+					id: old.id,
+					deposit,
+				})
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Migrated {translated} application keys to v1, reserved deposits of {reserved} keys with a shortfall of {shortfall:?}"
+			);
+			// Reserving reads and writes the account of the owner.
+			let accesses = translated.saturating_add(reserved).saturating_add(1);
+			T::DbWeight::get().reads_writes(accesses, accesses)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let keys = AppKeys::<T>::iter_keys().count() as u64;
+			Ok(keys.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let pre_keys = u64::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+			let post_keys = AppKeys::<T>::iter_values().count() as u64;
+			frame_support::ensure!(pre_keys == post_keys, "Application keys were lost");
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"Storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Backfills `AppIdToKey`, the reverse index of `AppKeys`.
+pub mod v2 {
+	use super::*;
+	use crate::AppIdToKey;
+
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to v2, on-chain storage version is {on_chain_version:?}"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut indexed = 0u64;
+			for (key, info) in AppKeys::<T>::iter() {
+				AppIdToKey::<T>::insert(info.id, key);
+				indexed = indexed.saturating_add(1);
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Indexed {indexed} application keys by id"
+			);
+			T::DbWeight::get().reads_writes(indexed.saturating_add(1), indexed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let keys = AppKeys::<T>::iter_keys().count() as u64;
+			Ok(keys.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let pre_keys = u64::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+			let indexed = AppIdToKey::<T>::iter_keys().count() as u64;
+			frame_support::ensure!(pre_keys == indexed, "Application keys were not indexed");
+			for (key, info) in AppKeys::<T>::iter() {
+				frame_support::ensure!(
+					AppIdToKey::<T>::get(info.id) == Some(key),
+					"Application key index is inconsistent"
+				);
+			}
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"Storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
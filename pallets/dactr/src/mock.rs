@@ -77,8 +77,19 @@ impl pallet_utility::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const AppKeyDeposit: Balance = 10 * AVAIL;
+	pub const AppMetadataDepositPerByte: Balance = AVAIL;
+	pub const MaxSponsoredFee: Balance = AVAIL;
+}
+
 #[derive_impl(da_control::config_preludes::TestDefaultConfig)]
-impl da_control::Config for Test {}
+impl da_control::Config for Test {
+	type AppKeyDeposit = AppKeyDeposit;
+	type AppMetadataDepositPerByte = AppMetadataDepositPerByte;
+	type Currency = Balances;
+	type MaxSponsoredFee = MaxSponsoredFee;
+}
 
 /// Create new externalities for `System` module tests.
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -1,5 +1,5 @@
 use avail_core::{BlockLengthColumns, BlockLengthRows, BLOCK_CHUNK_SIZE};
-use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::ReservableCurrency};
 use frame_system::{limits::BlockLength, RawOrigin};
 use sp_core::H256;
 
@@ -7,13 +7,18 @@ use crate::config_preludes::{
 	MaxAppDataLength, MaxBlockCols, MaxBlockRows, MinBlockCols, MinBlockRows,
 };
 use crate::{
-	mock::{new_test_ext, DataAvailability, RuntimeEvent, RuntimeOrigin, System, Test},
+	mock::{
+		new_test_ext, AppKeyDeposit, Balances, DataAvailability, RuntimeEvent, RuntimeOrigin,
+		System, Test,
+	},
 	AppDataFor, AppKeyFor, AppKeyInfoFor, Event, DA_DISPATCH_RATIO_PERBILL,
 };
 
 type Error = crate::Error<Test>;
 
 const ALICE: u64 = 1;
+const BOB: u64 = 2;
+const POOR: u64 = 4;
 
 mod create_application_key {
 	use super::*;
@@ -34,9 +39,11 @@ mod create_application_key {
 				DataAvailability::application_key(&new_key),
 				Some(AppKeyInfoFor::<Test> {
 					id: new_id,
-					owner: ALICE
+					owner: ALICE,
+					deposit: AppKeyDeposit::get(),
 				})
 			);
+			assert_eq!(Balances::reserved_balance(ALICE), AppKeyDeposit::get());
 
 			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyCreated {
 				key: new_key,
@@ -73,6 +80,17 @@ mod create_application_key {
 			assert_noop!(err, Error::AppKeyAlreadyExists);
 		})
 	}
+
+	#[test]
+	fn insufficient_balance_for_deposit() {
+		new_test_ext().execute_with(|| {
+			let poor: RuntimeOrigin = RawOrigin::Signed(POOR).into();
+			let new_key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+
+			let err = DataAvailability::create_application_key(poor, new_key);
+			assert_noop!(err, pallet_balances::Error::<Test>::InsufficientBalance);
+		})
+	}
 }
 
 mod submit_data {
@@ -118,6 +136,54 @@ mod submit_data {
 	}
 }
 
+mod submit_data_batch {
+	use super::*;
+	use avail_core::AppId;
+
+	#[test]
+	fn submit_data_batch() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let first = AppDataFor::<Test>::try_from(vec![b'X'; 8]).unwrap();
+			let second = AppDataFor::<Test>::try_from(vec![b'Y'; 16]).unwrap();
+			let blobs = vec![(AppId(1), first.clone()), (AppId(2), second.clone())];
+
+			assert_ok!(DataAvailability::submit_data_batch(alice, blobs));
+
+			for data in [first, second] {
+				let event = RuntimeEvent::DataAvailability(Event::DataSubmitted {
+					who: ALICE,
+					data_hash: H256(sp_io::hashing::blake2_256(&data)),
+				});
+				System::assert_has_event(event);
+			}
+		})
+	}
+
+	#[test]
+	fn batch_cannot_be_empty() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::submit_data_batch(alice, vec![]);
+			assert_noop!(err, Error::DataCannotBeEmpty);
+		})
+	}
+
+	#[test]
+	fn blobs_cannot_be_empty() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let data = AppDataFor::<Test>::try_from(vec![b'X'; 8]).unwrap();
+			let empty = AppDataFor::<Test>::try_from(vec![]).unwrap();
+			let blobs = vec![(AppId(1), data), (AppId(2), empty)];
+
+			let err = DataAvailability::submit_data_batch(alice, blobs);
+			assert_noop!(err, Error::DataCannotBeEmpty);
+		})
+	}
+}
+
 mod submit_block_length_proposal {
 	use super::*;
 
@@ -263,6 +329,344 @@ mod submit_block_length_proposal {
 	}
 }
 
+mod schedule_block_length_proposal {
+	use super::*;
+	use frame_support::{dispatch::DispatchClass, traits::Hooks, weights::Weight};
+	use frame_system::DynamicBlockLength;
+
+	fn block_length(rows: BlockLengthRows, cols: BlockLengthColumns) -> BlockLength {
+		BlockLength::with_normal_ratio(rows, cols, BLOCK_CHUNK_SIZE, DA_DISPATCH_RATIO_PERBILL)
+			.unwrap()
+	}
+
+	#[test]
+	fn scheduled_proposal_is_enacted_on_initialize() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(64);
+			let initial_block_length = System::block_length();
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root, rows.0, cols.0, 3
+			));
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthProposalScheduled {
+				rows,
+				cols,
+				when: 3,
+			});
+			System::assert_last_event(event);
+			assert_eq!(
+				DataAvailability::pending_block_length(),
+				Some((3, block_length(rows, cols)))
+			);
+
+			DataAvailability::on_initialize(2);
+			assert_eq!(System::block_length(), initial_block_length);
+
+			DataAvailability::on_initialize(3);
+			assert_eq!(System::block_length(), block_length(rows, cols));
+			assert_eq!(DataAvailability::pending_block_length(), None);
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthProposalEnacted { rows, cols });
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn scheduled_reduction_fails_with_high_block_weight() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(64);
+			let initial_block_length = block_length(BlockLengthRows(256), BlockLengthColumns(256));
+			DynamicBlockLength::<Test>::put(initial_block_length.clone());
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root, rows.0, cols.0, 3
+			));
+			System::register_extra_weight_unchecked(Weight::MAX, DispatchClass::Normal);
+
+			DataAvailability::on_initialize(3);
+			assert_eq!(System::block_length(), initial_block_length);
+			assert_eq!(DataAvailability::pending_block_length(), None);
+			let event =
+				RuntimeEvent::DataAvailability(Event::BlockLengthProposalFailed { rows, cols });
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn cancel_pending_proposal() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let rows = BlockLengthRows(128);
+			let cols = BlockLengthColumns(64);
+			let initial_block_length = System::block_length();
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root.clone(),
+				rows.0,
+				cols.0,
+				3
+			));
+			assert_ok!(DataAvailability::cancel_block_length_proposal(root));
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthProposalCancelled {
+				rows,
+				cols,
+				when: 3,
+			});
+			System::assert_last_event(event);
+
+			DataAvailability::on_initialize(3);
+			assert_eq!(System::block_length(), initial_block_length);
+		})
+	}
+
+	#[test]
+	fn nothing_to_cancel() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			let err = DataAvailability::cancel_block_length_proposal(root);
+			assert_noop!(err, Error::NoPendingBlockLengthProposal);
+		})
+	}
+
+	#[test]
+	fn only_one_pending_proposal() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			assert_ok!(DataAvailability::schedule_block_length_proposal(
+				root.clone(),
+				128,
+				64,
+				3
+			));
+			let err = DataAvailability::schedule_block_length_proposal(root, 64, 64, 4);
+			assert_noop!(err, Error::BlockLengthProposalAlreadyPending);
+		})
+	}
+
+	#[test]
+	fn enactment_block_must_be_in_the_future() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			let err = DataAvailability::schedule_block_length_proposal(root, 128, 64, 1);
+			assert_noop!(err, Error::EnactmentBlockInThePast);
+		})
+	}
+
+	#[test]
+	fn dimensions_are_checked() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			let err = DataAvailability::schedule_block_length_proposal(root, 100, 64, 3);
+			assert_noop!(err, Error::NotPowerOfTwo);
+		})
+	}
+
+	#[test]
+	fn only_root() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::schedule_block_length_proposal(alice.clone(), 128, 64, 3);
+			assert_noop!(err, BadOrigin);
+			let err = DataAvailability::cancel_block_length_proposal(alice);
+			assert_noop!(err, BadOrigin);
+		})
+	}
+}
+
+mod grid_auto_resize {
+	use super::*;
+	use crate::{GridAutoResizeConfig, GridUtilization, WeightInfo};
+	use frame_support::{
+		traits::{Get, Hooks},
+		BoundedVec,
+	};
+	use frame_system::DynamicBlockLength;
+	use sp_runtime::{traits::Zero, Perbill};
+
+	fn config() -> GridAutoResizeConfig {
+		GridAutoResizeConfig {
+			target: Perbill::from_percent(50),
+			step: 1,
+		}
+	}
+
+	fn block_length(rows: u32) -> BlockLength {
+		BlockLength::with_normal_ratio(
+			BlockLengthRows(rows),
+			BlockLengthColumns(64),
+			BLOCK_CHUNK_SIZE,
+			DA_DISPATCH_RATIO_PERBILL,
+		)
+		.unwrap()
+	}
+
+	fn resize_with_utilization(rows: u32, utilization: u32) -> BlockLengthRows {
+		let root: RuntimeOrigin = RawOrigin::Root.into();
+		assert_ok!(DataAvailability::set_grid_auto_resize(root, Some(config())));
+		DynamicBlockLength::<Test>::put(block_length(rows));
+
+		let samples = vec![Perbill::from_percent(utilization); 10];
+		GridUtilization::<Test>::put(BoundedVec::truncate_from(samples));
+		DataAvailability::on_initialize(2);
+
+		System::block_length().rows
+	}
+
+	#[test]
+	fn set_grid_auto_resize() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			assert_ok!(DataAvailability::set_grid_auto_resize(
+				root.clone(),
+				Some(config())
+			));
+			assert_eq!(DataAvailability::grid_auto_resize(), Some(config()));
+			let event = RuntimeEvent::DataAvailability(Event::GridAutoResizeSet {
+				config: Some(config()),
+			});
+			System::assert_last_event(event);
+
+			assert_ok!(DataAvailability::set_grid_auto_resize(root, None));
+			assert_eq!(DataAvailability::grid_auto_resize(), None);
+		})
+	}
+
+	#[test]
+	fn invalid_config() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let zero_target = GridAutoResizeConfig {
+				target: Perbill::zero(),
+				step: 1,
+			};
+			let zero_step = GridAutoResizeConfig {
+				step: 0,
+				..config()
+			};
+
+			let err = DataAvailability::set_grid_auto_resize(root.clone(), Some(zero_target));
+			assert_noop!(err, Error::InvalidGridAutoResizeConfig);
+			let err = DataAvailability::set_grid_auto_resize(root, Some(zero_step));
+			assert_noop!(err, Error::InvalidGridAutoResizeConfig);
+		})
+	}
+
+	#[test]
+	fn only_root() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::set_grid_auto_resize(alice, Some(config()));
+			assert_noop!(err, BadOrigin);
+		})
+	}
+
+	#[test]
+	fn rows_grow_over_target() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(resize_with_utilization(128, 90), BlockLengthRows(256));
+			assert!(GridUtilization::<Test>::get().is_empty());
+
+			let event = RuntimeEvent::DataAvailability(Event::BlockLengthAutoResized {
+				rows: BlockLengthRows(256),
+				cols: BlockLengthColumns(64),
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn rows_shrink_under_target() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(resize_with_utilization(128, 10), BlockLengthRows(64));
+		})
+	}
+
+	#[test]
+	fn rows_are_kept_around_target() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(resize_with_utilization(128, 40), BlockLengthRows(128));
+		})
+	}
+
+	#[test]
+	fn rows_are_bounded() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				resize_with_utilization(MaxBlockRows::get().0, 90),
+				MaxBlockRows::get()
+			);
+			assert_eq!(
+				resize_with_utilization(MinBlockRows::get().0, 1),
+				MinBlockRows::get()
+			);
+		})
+	}
+
+	#[test]
+	fn rows_are_kept_until_window_is_full() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			assert_ok!(DataAvailability::set_grid_auto_resize(root, Some(config())));
+			DynamicBlockLength::<Test>::put(block_length(128));
+
+			let samples = vec![Perbill::from_percent(90); 9];
+			GridUtilization::<Test>::put(BoundedVec::truncate_from(samples));
+			DataAvailability::on_initialize(2);
+
+			assert_eq!(System::block_length().rows, BlockLengthRows(128));
+		})
+	}
+
+	#[test]
+	fn on_initialize_weight_scales_with_samples() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			assert_ok!(DataAvailability::set_grid_auto_resize(root, Some(config())));
+			let pending_weight = <Test as frame_system::Config>::DbWeight::get().reads(1);
+
+			for len in [1, 9] {
+				let samples = vec![Perbill::from_percent(50); len];
+				GridUtilization::<Test>::put(BoundedVec::truncate_from(samples));
+
+				let expected = <() as WeightInfo>::auto_resize_block_length(len as u32);
+				assert_eq!(
+					DataAvailability::on_initialize(2),
+					pending_weight.saturating_add(expected)
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn utilization_is_recorded_on_finalize() {
+		new_test_ext().execute_with(|| {
+			DataAvailability::on_finalize(1);
+			assert!(GridUtilization::<Test>::get().is_empty());
+
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			assert_ok!(DataAvailability::set_grid_auto_resize(root, Some(config())));
+			let samples = vec![Perbill::from_percent(90); 10];
+			GridUtilization::<Test>::put(BoundedVec::truncate_from(samples));
+
+			DataAvailability::on_finalize(1);
+			let samples = GridUtilization::<Test>::get();
+			assert_eq!(samples.len(), 10);
+			assert_eq!(samples.last(), Some(&Perbill::zero()));
+		})
+	}
+}
+
 mod set_application_key {
 	use super::*;
 
@@ -381,3 +785,790 @@ mod set_submit_data_fee_modifier {
 		})
 	}
 }
+
+mod set_submit_data_fee_modifier_by_app {
+	use super::*;
+	use crate::{weight_helper, SubmitDataFeeModifier, SubmitDataFeeModifierByApp};
+	use avail_core::AppId;
+	use frame_support::dispatch::DispatchFeeModifier;
+
+	const MODIFIER: DispatchFeeModifier = DispatchFeeModifier {
+		weight_maximum_fee: None,
+		weight_fee_divider: Some(2),
+		weight_fee_multiplier: None,
+	};
+
+	#[test]
+	fn set_submit_data_fee_modifier_by_app() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			assert_ok!(DataAvailability::set_submit_data_fee_modifier_by_app(
+				root,
+				AppId(1),
+				Some(MODIFIER)
+			));
+			assert_eq!(
+				SubmitDataFeeModifierByApp::<Test>::get(AppId(1)),
+				Some(MODIFIER)
+			);
+
+			let event = RuntimeEvent::DataAvailability(Event::SubmitDataFeeModifierByAppSet {
+				id: AppId(1),
+				value: Some(MODIFIER),
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn only_sudo_can_call_this() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::set_submit_data_fee_modifier_by_app(alice, AppId(1), None);
+			assert_noop!(err, BadOrigin);
+		})
+	}
+
+	#[test]
+	fn falls_back_to_global_modifier() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let global = DispatchFeeModifier {
+				weight_maximum_fee: Some(100),
+				weight_fee_divider: None,
+				weight_fee_multiplier: None,
+			};
+			SubmitDataFeeModifier::<Test>::put(global);
+
+			assert_ok!(DataAvailability::set_submit_data_fee_modifier_by_app(
+				root.clone(),
+				AppId(1),
+				Some(MODIFIER)
+			));
+			assert_eq!(
+				weight_helper::submit_data_fee_modifier::<Test>(AppId(1)),
+				MODIFIER
+			);
+			assert_eq!(
+				weight_helper::submit_data_fee_modifier::<Test>(AppId(2)),
+				global
+			);
+
+			assert_ok!(DataAvailability::set_submit_data_fee_modifier_by_app(
+				root,
+				AppId(1),
+				None
+			));
+			assert_eq!(
+				weight_helper::submit_data_fee_modifier::<Test>(AppId(1)),
+				global
+			);
+		})
+	}
+}
+
+mod transfer_application_key {
+	use super::*;
+
+	#[test]
+	fn transfer_application_key() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+			let id = DataAvailability::application_key(&key).unwrap().id;
+
+			assert_ok!(DataAvailability::transfer_application_key(
+				alice,
+				key.clone(),
+				BOB
+			));
+			assert_eq!(
+				DataAvailability::application_key(&key),
+				Some(AppKeyInfoFor::<Test> {
+					id,
+					owner: BOB,
+					deposit: AppKeyDeposit::get(),
+				})
+			);
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyTransferred {
+				key,
+				id,
+				old_owner: ALICE,
+				new_owner: BOB,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn not_app_key_owner() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+
+			let err = DataAvailability::transfer_application_key(alice, key, ALICE);
+			assert_noop!(err, Error::NotAppKeyOwner);
+		})
+	}
+
+	#[test]
+	fn unknown_app_key() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"NotExisting".to_vec()).unwrap();
+
+			let err = DataAvailability::transfer_application_key(alice, key, BOB);
+			assert_noop!(err, Error::UnknownAppKey);
+		})
+	}
+
+	#[test]
+	fn deposit_is_moved_to_new_owner() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_eq!(Balances::reserved_balance(ALICE), AppKeyDeposit::get());
+
+			assert_ok!(DataAvailability::transfer_application_key(alice, key, BOB));
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::reserved_balance(BOB), AppKeyDeposit::get());
+		})
+	}
+
+	#[test]
+	fn new_owner_cannot_afford_deposit() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap();
+
+			let err = DataAvailability::transfer_application_key(alice, key, POOR);
+			assert_noop!(err, pallet_balances::Error::<Test>::InsufficientBalance);
+		})
+	}
+}
+
+mod retire_application_key {
+	use super::*;
+
+	#[test]
+	fn retire_application_key() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+			let id = DataAvailability::application_key(&key).unwrap().id;
+
+			assert!(!DataAvailability::is_app_id_retired(id));
+			assert_ok!(DataAvailability::retire_application_key(bob, key.clone()));
+			assert_eq!(DataAvailability::application_key(&key), None);
+			assert!(DataAvailability::is_app_id_retired(id));
+
+			let event = RuntimeEvent::DataAvailability(Event::ApplicationKeyRetired {
+				key,
+				id,
+				owner: BOB,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn not_app_key_owner() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+
+			let err = DataAvailability::retire_application_key(alice, key);
+			assert_noop!(err, Error::NotAppKeyOwner);
+		})
+	}
+
+	#[test]
+	fn retired_key_cannot_be_transferred() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			let key = AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap();
+
+			assert_ok!(DataAvailability::retire_application_key(
+				bob.clone(),
+				key.clone()
+			));
+
+			let err = DataAvailability::transfer_application_key(bob, key, ALICE);
+			assert_noop!(err, Error::UnknownAppKey);
+		})
+	}
+
+	#[test]
+	fn deposit_is_unreserved() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			assert_eq!(Balances::reserved_balance(ALICE), AppKeyDeposit::get());
+
+			assert_ok!(DataAvailability::retire_application_key(alice, key));
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+		})
+	}
+}
+
+mod set_app_data_quota {
+	use super::*;
+	use crate::AppDataQuota;
+	use avail_core::AppId;
+
+	const QUOTA: AppDataQuota = AppDataQuota {
+		max_bytes: 1_024,
+		max_scalars: 64,
+	};
+
+	#[test]
+	fn set_app_data_quota() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			assert_eq!(DataAvailability::app_data_quota(AppId(1)), None);
+			assert_ok!(DataAvailability::set_app_data_quota(
+				root,
+				AppId(1),
+				Some(QUOTA)
+			));
+			assert_eq!(DataAvailability::app_data_quota(AppId(1)), Some(QUOTA));
+
+			let event = RuntimeEvent::DataAvailability(Event::AppDataQuotaSet {
+				id: AppId(1),
+				quota: Some(QUOTA),
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn remove_app_data_quota() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+
+			assert_ok!(DataAvailability::set_app_data_quota(
+				root.clone(),
+				AppId(1),
+				Some(QUOTA)
+			));
+			assert_ok!(DataAvailability::set_app_data_quota(root, AppId(1), None));
+			assert_eq!(DataAvailability::app_data_quota(AppId(1)), None);
+		})
+	}
+
+	#[test]
+	fn bad_origin() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::set_app_data_quota(alice, AppId(1), Some(QUOTA));
+			assert_noop!(err, BadOrigin);
+		})
+	}
+}
+
+mod sponsorship {
+	use super::*;
+	use crate::SponsoredAllowances;
+	use avail_core::{currency::AVAIL, AppId};
+	use frame_support::traits::Currency;
+
+	fn avail_key() -> AppKeyFor<Test> {
+		AppKeyFor::<Test>::try_from(b"Avail".to_vec()).unwrap()
+	}
+
+	#[test]
+	fn fund_sponsorship() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let pot = DataAvailability::sponsor_pot_account(AppId(0));
+			let amount = 100 * AVAIL;
+
+			assert_ok!(DataAvailability::fund_sponsorship(
+				alice,
+				avail_key(),
+				amount
+			));
+			assert_eq!(Balances::free_balance(pot), amount);
+
+			let event = RuntimeEvent::DataAvailability(Event::SponsorshipFunded {
+				id: AppId(0),
+				owner: ALICE,
+				amount,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn withdraw_sponsorship() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let pot = DataAvailability::sponsor_pot_account(AppId(0));
+			let amount = 100 * AVAIL;
+
+			assert_ok!(DataAvailability::fund_sponsorship(
+				alice.clone(),
+				avail_key(),
+				amount
+			));
+			assert_ok!(DataAvailability::withdraw_sponsorship(
+				alice,
+				avail_key(),
+				amount
+			));
+			assert_eq!(Balances::free_balance(pot), 0);
+
+			let event = RuntimeEvent::DataAvailability(Event::SponsorshipWithdrawn {
+				id: AppId(0),
+				owner: ALICE,
+				amount,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn only_owner_can_manage_sponsorship() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			let err = DataAvailability::fund_sponsorship(bob.clone(), avail_key(), AVAIL);
+			assert_noop!(err, Error::NotAppKeyOwner);
+			let err = DataAvailability::withdraw_sponsorship(bob.clone(), avail_key(), AVAIL);
+			assert_noop!(err, Error::NotAppKeyOwner);
+			let err = DataAvailability::set_sponsored_allowance(bob.clone(), avail_key(), BOB, 1);
+			assert_noop!(err, Error::NotAppKeyOwner);
+			let err = DataAvailability::revoke_sponsored_allowance(bob, avail_key(), BOB);
+			assert_noop!(err, Error::NotAppKeyOwner);
+		})
+	}
+
+	#[test]
+	fn set_and_revoke_sponsored_allowance() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			assert_ok!(DataAvailability::set_sponsored_allowance(
+				alice.clone(),
+				avail_key(),
+				BOB,
+				2
+			));
+			assert_eq!(SponsoredAllowances::<Test>::get(AppId(0), BOB), 2);
+			assert_eq!(
+				DataAvailability::sponsor_of(AppId(0), &BOB),
+				Some(DataAvailability::sponsor_pot_account(AppId(0)))
+			);
+
+			DataAvailability::consume_sponsored_allowance(AppId(0), &BOB);
+			assert_eq!(SponsoredAllowances::<Test>::get(AppId(0), BOB), 1);
+
+			assert_ok!(DataAvailability::revoke_sponsored_allowance(
+				alice.clone(),
+				avail_key(),
+				BOB
+			));
+			assert_eq!(DataAvailability::sponsor_of(AppId(0), &BOB), None);
+
+			let event = RuntimeEvent::DataAvailability(Event::SponsoredAllowanceRevoked {
+				id: AppId(0),
+				signer: BOB,
+			});
+			System::assert_last_event(event);
+
+			let err = DataAvailability::revoke_sponsored_allowance(alice, avail_key(), BOB);
+			assert_noop!(err, Error::UnknownSponsoredSigner);
+		})
+	}
+
+	#[test]
+	fn retire_returns_sponsorship_pot() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let pot = DataAvailability::sponsor_pot_account(AppId(0));
+			let initial_balance = Balances::free_balance(ALICE);
+
+			assert_ok!(DataAvailability::fund_sponsorship(
+				alice.clone(),
+				avail_key(),
+				100 * AVAIL
+			));
+			assert_ok!(DataAvailability::retire_application_key(alice, avail_key()));
+			assert_eq!(Balances::free_balance(pot), 0);
+			assert_eq!(Balances::free_balance(ALICE), initial_balance);
+		})
+	}
+}
+
+mod app_metadata {
+	use super::*;
+	use crate::{AppDataEncoding, AppMetadata, AppMetadataFor};
+	use avail_core::{currency::AVAIL, AppId};
+
+	fn metadata(name: &[u8]) -> Box<AppMetadataFor<Test>> {
+		Box::new(AppMetadata {
+			name: name.to_vec().try_into().unwrap(),
+			namespace: b"ns".to_vec().try_into().unwrap(),
+			website: b"a.io".to_vec().try_into().unwrap(),
+			encoding: AppDataEncoding::Raw,
+		})
+	}
+
+	fn reserved_key() -> AppKeyFor<Test> {
+		AppKeyFor::<Test>::try_from(b"Reserved-1".to_vec()).unwrap()
+	}
+
+	#[test]
+	fn set_app_metadata() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert_ok!(DataAvailability::set_app_metadata(
+				bob,
+				reserved_key(),
+				metadata(b"Rollup")
+			));
+
+			// 16 encoded bytes.
+			let deposit = 16 * AVAIL;
+			assert_eq!(
+				DataAvailability::app_metadata(AppId(1)),
+				Some((*metadata(b"Rollup"), deposit))
+			);
+			assert_eq!(Balances::reserved_balance(BOB), deposit);
+
+			let event = RuntimeEvent::DataAvailability(Event::AppMetadataSet {
+				id: AppId(1),
+				deposit,
+			});
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn update_adjusts_deposit() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert_ok!(DataAvailability::set_app_metadata(
+				bob.clone(),
+				reserved_key(),
+				metadata(b"Rollup")
+			));
+			assert_ok!(DataAvailability::set_app_metadata(
+				bob.clone(),
+				reserved_key(),
+				metadata(b"Rollup with a longer name")
+			));
+			assert_eq!(Balances::reserved_balance(BOB), 35 * AVAIL);
+
+			assert_ok!(DataAvailability::set_app_metadata(
+				bob,
+				reserved_key(),
+				metadata(b"R")
+			));
+			assert_eq!(Balances::reserved_balance(BOB), 11 * AVAIL);
+		})
+	}
+
+	#[test]
+	fn clear_app_metadata() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert_ok!(DataAvailability::set_app_metadata(
+				bob.clone(),
+				reserved_key(),
+				metadata(b"Rollup")
+			));
+			assert_ok!(DataAvailability::clear_app_metadata(bob, reserved_key()));
+
+			assert_eq!(DataAvailability::app_metadata(AppId(1)), None);
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			let event = RuntimeEvent::DataAvailability(Event::AppMetadataCleared { id: AppId(1) });
+			System::assert_last_event(event);
+		})
+	}
+
+	#[test]
+	fn nothing_to_clear() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			let err = DataAvailability::clear_app_metadata(bob, reserved_key());
+			assert_noop!(err, Error::UnknownAppMetadata);
+		})
+	}
+
+	#[test]
+	fn not_app_key_owner() {
+		new_test_ext().execute_with(|| {
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+
+			let err = DataAvailability::set_app_metadata(
+				alice.clone(),
+				reserved_key(),
+				metadata(b"Rollup"),
+			);
+			assert_noop!(err, Error::NotAppKeyOwner);
+			let err = DataAvailability::clear_app_metadata(alice, reserved_key());
+			assert_noop!(err, Error::NotAppKeyOwner);
+		})
+	}
+
+	#[test]
+	fn insufficient_balance() {
+		new_test_ext().execute_with(|| {
+			let poor: RuntimeOrigin = RawOrigin::Signed(POOR).into();
+			let key = AppKeyFor::<Test>::try_from(b"Poor".to_vec()).unwrap();
+			crate::AppKeys::<Test>::insert(&key, AppKeyInfoFor::<Test>::new(POOR, AppId(3), 0));
+
+			let err = DataAvailability::set_app_metadata(poor, key, metadata(b"Rollup"));
+			assert_noop!(err, pallet_balances::Error::<Test>::InsufficientBalance);
+		})
+	}
+
+	#[test]
+	fn transfer_moves_deposit() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert_ok!(DataAvailability::set_app_metadata(
+				bob.clone(),
+				reserved_key(),
+				metadata(b"Rollup")
+			));
+			assert_ok!(DataAvailability::transfer_application_key(
+				bob,
+				reserved_key(),
+				ALICE
+			));
+
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			assert_eq!(
+				Balances::reserved_balance(ALICE),
+				AppKeyDeposit::get() + 16 * AVAIL
+			);
+		})
+	}
+
+	#[test]
+	fn retire_returns_deposit() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+
+			assert_ok!(DataAvailability::set_app_metadata(
+				bob.clone(),
+				reserved_key(),
+				metadata(b"Rollup")
+			));
+			assert_ok!(DataAvailability::retire_application_key(
+				bob,
+				reserved_key()
+			));
+
+			assert_eq!(DataAvailability::app_metadata(AppId(1)), None);
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+		})
+	}
+
+	#[test]
+	fn apps_are_paginated() {
+		new_test_ext().execute_with(|| {
+			let bob: RuntimeOrigin = RawOrigin::Signed(BOB).into();
+			assert_ok!(DataAvailability::set_app_metadata(
+				bob,
+				reserved_key(),
+				metadata(b"Rollup")
+			));
+
+			let all = DataAvailability::apps(None, 10);
+			assert_eq!(all.len(), 3);
+			let (_, _, metadata_of_reserved) = all
+				.iter()
+				.find(|(key, _, _)| *key == reserved_key())
+				.unwrap();
+			assert_eq!(metadata_of_reserved.as_ref(), Some(&*metadata(b"Rollup")));
+
+			let first_page = DataAvailability::apps(None, 2);
+			let (last_key, _, _) = first_page.last().cloned().unwrap();
+			let second_page = DataAvailability::apps(Some(last_key), 2);
+			assert_eq!(first_page.len(), 2);
+			assert_eq!(second_page.len(), 1);
+			assert_eq!([first_page, second_page].concat(), all);
+		})
+	}
+}
+
+mod app_key_lookup {
+	use super::*;
+
+	#[test]
+	fn app_key_by_id_follows_key_lifecycle() {
+		new_test_ext().execute_with(|| {
+			let root: RuntimeOrigin = RawOrigin::Root.into();
+			let alice: RuntimeOrigin = RawOrigin::Signed(ALICE).into();
+			let id = DataAvailability::peek_next_application_id();
+			let key = AppKeyFor::<Test>::try_from(b"New App".to_vec()).unwrap();
+			let new_key = AppKeyFor::<Test>::try_from(b"Renamed App".to_vec()).unwrap();
+
+			assert_eq!(DataAvailability::app_key_by_id(id), None);
+			assert_ok!(DataAvailability::create_application_key(
+				alice.clone(),
+				key.clone()
+			));
+			let (found, info) = DataAvailability::app_key_by_id(id).unwrap();
+			assert_eq!((found, info.owner), (key.clone(), ALICE));
+
+			assert_ok!(DataAvailability::set_application_key(
+				root,
+				key,
+				new_key.clone()
+			));
+			let (found, _) = DataAvailability::app_key_by_id(id).unwrap();
+			assert_eq!(found, new_key);
+
+			assert_ok!(DataAvailability::retire_application_key(alice, new_key));
+			assert_eq!(DataAvailability::app_key_by_id(id), None);
+		})
+	}
+
+	#[test]
+	fn app_keys_by_owner() {
+		new_test_ext().execute_with(|| {
+			let mut keys: Vec<_> = DataAvailability::app_keys_by_owner(&BOB)
+				.into_iter()
+				.map(|(key, _)| key.to_vec())
+				.collect();
+			keys.sort();
+			assert_eq!(
+				keys,
+				vec![b"A Brave New World".to_vec(), b"Reserved-1".to_vec()]
+			);
+			assert!(DataAvailability::app_keys_by_owner(&POOR).is_empty());
+		})
+	}
+}
+
+mod migrations {
+	use super::*;
+	use crate::{
+		migrations::{v1::MigrateToV1, v2::MigrateToV2},
+		AppIdToKey, AppKeys,
+	};
+	use avail_core::AppId;
+	use codec::Encode;
+	use frame_support::{
+		storage::unhashed,
+		traits::{ConstU32, Currency, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	type GenesisAppIds = ConstU32<2>;
+
+	/// Rewrites `key` using the v0 layout, returning its owner and id.
+	fn put_v0_key(key: &[u8]) -> (u64, AppId) {
+		let key = AppKeyFor::<Test>::try_from(key.to_vec()).unwrap();
+		let info = DataAvailability::application_key(&key).unwrap();
+		let raw_key = AppKeys::<Test>::hashed_key_for(&key);
+		unhashed::put_raw(&raw_key, &(info.owner, info.id).encode());
+		(info.owner, info.id)
+	}
+
+	fn v1_key(key: &[u8]) -> Option<AppKeyInfoFor<Test>> {
+		DataAvailability::application_key(&AppKeyFor::<Test>::try_from(key.to_vec()).unwrap())
+	}
+
+	#[test]
+	fn migrate_to_v1_grandfathers_genesis_keys() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<DataAvailability>();
+			let (owner, id) = put_v0_key(b"Avail");
+
+			MigrateToV1::<Test, GenesisAppIds>::on_runtime_upgrade();
+
+			assert_eq!(
+				v1_key(b"Avail"),
+				Some(AppKeyInfoFor::<Test> {
+					id,
+					owner,
+					deposit: 0,
+				})
+			);
+			assert_eq!(Balances::reserved_balance(owner), 0);
+			assert_eq!(DataAvailability::on_chain_storage_version(), 1);
+		})
+	}
+
+	#[test]
+	fn migrate_to_v1_reserves_deposit_of_later_keys() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<DataAvailability>();
+			let (owner, id) = put_v0_key(b"A Brave New World");
+
+			MigrateToV1::<Test, GenesisAppIds>::on_runtime_upgrade();
+
+			assert_eq!(
+				v1_key(b"A Brave New World"),
+				Some(AppKeyInfoFor::<Test> {
+					id,
+					owner,
+					deposit: AppKeyDeposit::get(),
+				})
+			);
+			assert_eq!(Balances::reserved_balance(owner), AppKeyDeposit::get());
+		})
+	}
+
+	#[test]
+	fn migrate_to_v1_records_deposit_shortfall() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<DataAvailability>();
+			let (owner, id) = put_v0_key(b"A Brave New World");
+			let available = AppKeyDeposit::get() / 2;
+			Balances::make_free_balance_be(&owner, available + Balances::minimum_balance());
+
+			MigrateToV1::<Test, GenesisAppIds>::on_runtime_upgrade();
+
+			assert_eq!(
+				v1_key(b"A Brave New World"),
+				Some(AppKeyInfoFor::<Test> {
+					id,
+					owner,
+					deposit: available,
+				})
+			);
+			assert_eq!(Balances::reserved_balance(owner), available);
+		})
+	}
+
+	#[test]
+	fn migrate_to_v2_backfills_app_id_index() {
+		new_test_ext().execute_with(|| {
+			let _ = AppIdToKey::<Test>::clear(u32::MAX, None);
+			StorageVersion::new(1).put::<DataAvailability>();
+
+			MigrateToV2::<Test>::on_runtime_upgrade();
+
+			for (key, info) in AppKeys::<Test>::iter() {
+				assert_eq!(AppIdToKey::<Test>::get(info.id), Some(key));
+			}
+			assert_eq!(AppIdToKey::<Test>::iter_keys().count(), 3);
+			assert_eq!(DataAvailability::on_chain_storage_version(), 2);
+		})
+	}
+}
@@ -9,7 +9,7 @@ use frame_support::{
 };
 use frame_system::{CheckEra, CheckNonce, CheckWeight};
 use pallet_transaction_payment::FungibleAdapter;
-use sp_runtime::traits::{BlakeTwo256, ConstU32, IdentityLookup, TrailingZeroInput};
+use sp_runtime::traits::{BlakeTwo256, ConstU128, ConstU32, IdentityLookup, TrailingZeroInput};
 use sp_std::marker::PhantomData;
 
 pub mod custom;
@@ -173,7 +173,12 @@ impl pallet_balances::Config for Runtime {
 }
 
 #[derive_impl(da_control::config_preludes::TestDefaultConfig)]
-impl da_control::Config for Runtime {}
+impl da_control::Config for Runtime {
+	type AppKeyDeposit = ConstU128<1>;
+	type AppMetadataDepositPerByte = ConstU128<1>;
+	type Currency = Balances;
+	type MaxSponsoredFee = ConstU128<1>;
+}
 
 impl custom::custom::Config for Runtime {}
 
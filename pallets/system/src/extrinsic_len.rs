@@ -46,6 +46,11 @@ impl<S: Get<u32>> PaddedExtrinsicLen<S> {
 	pub fn num_scalars(&self) -> u32 {
 		self.num_scalars
 	}
+
+	/// Returns the sum of the lengths of all Txs.
+	pub fn data_len(&self) -> u32 {
+		self.tx_lens.iter().sum()
+	}
 }
 
 impl<S: Get<u32>> Default for PaddedExtrinsicLen<S> {
@@ -89,6 +94,22 @@ impl<SID: Get<u32>, STX: Get<u32>> ExtrinsicLen<SID, STX> {
 			.sum()
 	}
 
+	/// Returns the length of the Txs submitted using `id`.
+	pub fn app_len(&self, id: AppId) -> u32 {
+		self.padded
+			.get(&id)
+			.map(PaddedExtrinsicLen::data_len)
+			.unwrap_or_default()
+	}
+
+	/// Returns the number of scalars used by the Txs submitted using `id`.
+	pub fn app_num_scalars(&self, id: AppId) -> u32 {
+		self.padded
+			.get(&id)
+			.map(PaddedExtrinsicLen::num_scalars)
+			.unwrap_or_default()
+	}
+
 	pub fn add_raw(&mut self, len: u32) -> Option<u32> {
 		let mut next_raw = self.raw.checked_add(len)?;
 		swap(&mut self.raw, &mut next_raw);
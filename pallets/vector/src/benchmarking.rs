@@ -1,22 +1,37 @@
 use crate::{
-	state::Configuration, BalanceOf, Call, Config, ConfigurationStorage, ExecutionStateRoots,
-	FunctionIds, FunctionInput, FunctionOutput, FunctionProof, Headers, Pallet, ProofInput,
-	PublicValuesInput, RotateVerificationKey, StepVerificationKey, Updater, ValidProof,
+	state::{
+		ConfigChange, Configuration, ExecutionFee, PendingConfigChange, ProofSystem,
+		TransferDirection, UpdaterSelection,
+	},
+	AssetIdOf, BalanceOf, BroadcasterLogTopics, Broadcasters, Call, Config, ConfigurationStorage,
+	ExecutionFees, ExecutionStateRoots, FulfilledSlots, FulfilledSlotsBounds, FunctionIds,
+	FunctionInput, FunctionOutput, FunctionProof, Headers, OutboundMessage, OutboundMessageIds,
+	OutboundMessageIdsBounds, OutboundMessageStatus, OutboundMessages, Pallet, PausedTransfers,
+	PendingConfigChanges, PeriodPruningCursor, ProofInput, PublicValuesInput, ReceiptsRootBranch,
+	RegisteredAssets, RotateVerificationKey, StepVerificationKey, Timestamps, TransferCaps,
+	TransferUsage, Updater, Updaters, ValidProof, WhitelistedDomains, RECEIPTS_ROOT_GINDEX,
 };
 use avail_core::data_proof::BOUNDED_DATA_MAX_LENGTH;
-use avail_core::data_proof::{AddressedMessage, Message};
+use avail_core::data_proof::{tx_uid, AddressedMessage, Message, MessageType};
+use codec::Decode;
 use frame_benchmarking::{
 	impl_benchmark_test_suite, v2::benchmarks, whitelisted_caller, BenchmarkError,
 };
 use frame_support::traits::DefensiveTruncateFrom;
-use frame_support::{traits::Currency, BoundedVec};
+use frame_support::{traits::Currency, weights::WeightMeter, BoundedVec};
 use frame_system::RawOrigin;
 use hex_literal::hex;
-use sp_core::{Get, H256};
-use sp_runtime::traits::Bounded;
-use sp_std::vec;
+use rlp::RlpStream;
+use sp_core::{Get, H256, U256};
+use sp_io::hashing::{keccak_256, sha2_256};
+use sp_runtime::{
+	traits::{Bounded, SaturatedConversion, TrailingZeroInput},
+	Permill,
+};
+use sp_std::{vec, vec::Vec};
 
 const ACCOUNT1: [u8; 32] = [2u8; 32];
+const MESSAGE_LOG_TOPIC: H256 = H256([1u8; 32]);
 pub const STEP_FUNCTION_ID: H256 = H256(hex!(
 	"af44af6890508b3b7f6910d4a4570a0d524769a23ce340b2c7400e140ad168ab"
 ));
@@ -175,7 +190,7 @@ const SP1_VERIFICATION_KEY: [u8; 32] =
 )]
 mod benchmarks {
 	use super::*;
-	use crate::{Broadcasters, Head, SP1VerificationKey, SyncCommitteeHashes};
+	use crate::{Head, SP1VerificationKey, SyncCommitteeHashes, SyncCommitteePoseidons};
 	use sp_runtime::traits::AccountIdConversion;
 
 	#[benchmark]
@@ -206,6 +221,10 @@ mod benchmarks {
 
 		// ACCOUNT1 needs to have enough funds to send 1 token
 		T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value() / 2u32.into());
+		TransferCaps::<T>::set(
+			(domain, TransferDirection::Outbound, H256::zero()),
+			Some(u128::MAX),
+		);
 
 		#[extrinsic_call]
 		send_message(origin, message, to, domain);
@@ -379,6 +398,16 @@ mod benchmarks {
 		// ACCOUNT1 needs to have enough funds to send 1 token
 		T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value() / 2u32.into());
 		T::Currency::make_free_balance_be(&pot, BalanceOf::<T>::max_value() / 2u32.into());
+		TransferCaps::<T>::set(
+			(2, TransferDirection::Inbound, H256::zero()),
+			Some(u128::MAX),
+		);
+		ExecutionFees::<T>::insert(
+			2,
+			ExecutionFee {
+				fungible_token: Permill::from_percent(1),
+			},
+		);
 
 		let account_proof = get_valid_account_proof();
 		let storage_proof = get_valid_storage_proof();
@@ -429,6 +458,32 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn verify_account_proof() -> Result<(), BenchmarkError> {
+		let slot = 8581263;
+		Broadcasters::<T>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+
+		ExecutionStateRoots::<T>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		let account_proof = get_valid_account_proof();
+
+		#[block]
+		{
+			Pallet::<T>::broadcaster_storage_root(slot, 2, &account_proof)?;
+		}
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_function_ids() -> Result<(), BenchmarkError> {
 		let origin = RawOrigin::Root;
@@ -530,17 +585,410 @@ mod benchmarks {
 
 	#[benchmark]
 	fn enable_mock() -> Result<(), BenchmarkError> {
+		// The call only exists with the `vector-mock` feature.
+		#[block]
+		{
+			#[cfg(any(test, feature = "vector-mock"))]
+			Pallet::<T>::enable_mock(RawOrigin::Root.into(), true).unwrap();
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_asset() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let asset_id = bridged_asset_id::<T>();
+
+		#[extrinsic_call]
+		_(origin, H256(ACCOUNT1), asset_id);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_asset() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		RegisteredAssets::<T>::insert(H256(ACCOUNT1), bridged_asset_id::<T>());
+
+		#[extrinsic_call]
+		_(origin, H256(ACCOUNT1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_slot() -> Result<(), BenchmarkError> {
+		let slot = 8581263;
+		Head::<T>::set(slot + T::SlotRetention::get() + 1);
+		Headers::<T>::set(slot, H256(ACCOUNT1));
+		Timestamps::<T>::set(slot, 1);
+		ExecutionStateRoots::<T>::set(slot, H256(ACCOUNT1));
+		FulfilledSlots::<T>::insert(0, slot);
+		FulfilledSlotsBounds::<T>::set((0, 1));
+
+		#[block]
+		{
+			Pallet::<T>::prune_slots(&mut WeightMeter::new());
+		}
+
+		assert_eq!(Headers::<T>::get(slot), H256::zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_period() -> Result<(), BenchmarkError> {
+		let slots_per_period = 8192;
+		let period = 754;
+		Head::<T>::set((period + T::PeriodRetention::get() + 1) * slots_per_period);
+		ConfigurationStorage::<T>::set(Configuration {
+			slots_per_period,
+			finality_threshold: 342,
+		});
+		SyncCommitteePoseidons::<T>::set(period, U256::MAX);
+		SyncCommitteeHashes::<T>::set(period, H256(ACCOUNT1));
+		PeriodPruningCursor::<T>::set(period);
+
+		#[block]
+		{
+			Pallet::<T>::prune_periods(&mut WeightMeter::new());
+		}
+
+		assert_eq!(SyncCommitteeHashes::<T>::get(period), H256::zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_outbound_message() -> Result<(), BenchmarkError> {
+		let message_id = tx_uid(1, 1);
+		let message = OutboundMessage {
+			from: T::AccountId::from(ACCOUNT1),
+			to: H256(ACCOUNT1),
+			destination_domain: 2,
+			message_type: MessageType::ArbitraryMessage,
+			block_number: 1,
+			status: OutboundMessageStatus::Sent,
+		};
+		OutboundMessages::<T>::insert(message_id, (0, message));
+		OutboundMessageIds::<T>::insert(0, message_id);
+		OutboundMessageIdsBounds::<T>::set((0, 1));
+		frame_system::Pallet::<T>::set_block_number(
+			(T::OutboundMessageRetention::get() + 2).into(),
+		);
+
+		#[block]
+		{
+			Pallet::<T>::prune_outbound_messages(&mut WeightMeter::new());
+		}
+
+		assert!(OutboundMessages::<T>::get(message_id).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_light_client_state(
+		s: Linear<0, 1024>,
+		p: Linear<0, 1024>,
+	) -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let slots_per_period = 8192;
+		Head::<T>::set((1024 + T::PeriodRetention::get() + 1) * slots_per_period);
+		ConfigurationStorage::<T>::set(Configuration {
+			slots_per_period,
+			finality_threshold: 342,
+		});
+
+		let slots: Vec<u64> = (0..s as u64).collect();
+		for slot in slots.iter() {
+			Headers::<T>::set(*slot, H256(ACCOUNT1));
+			Timestamps::<T>::set(*slot, 1);
+			ExecutionStateRoots::<T>::set(*slot, H256(ACCOUNT1));
+		}
+		let periods: Vec<u64> = (0..p as u64).collect();
+		for period in periods.iter() {
+			SyncCommitteePoseidons::<T>::set(*period, U256::MAX);
+			SyncCommitteeHashes::<T>::set(*period, H256(ACCOUNT1));
+		}
+		let slots = BoundedVec::defensive_truncate_from(slots);
+		let periods = BoundedVec::defensive_truncate_from(periods);
+
+		#[extrinsic_call]
+		_(origin, slots, periods);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_transfer_cap() -> Result<(), BenchmarkError> {
 		let origin = RawOrigin::Root;
 
 		#[extrinsic_call]
-		_(origin, true);
+		_(
+			origin,
+			2,
+			TransferDirection::Outbound,
+			H256::zero(),
+			Some(u128::MAX),
+		);
 
 		Ok(())
 	}
 
+	#[benchmark]
+	fn resume_transfers() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let key = (2, TransferDirection::Outbound, H256::zero());
+		PausedTransfers::<T>::insert(key, true);
+		TransferUsage::<T>::insert(key, (0, u128::MAX));
+
+		#[extrinsic_call]
+		_(origin, 2, TransferDirection::Outbound, H256::zero());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_execution_fee() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let fee = ExecutionFee {
+			fungible_token: Permill::from_percent(1),
+		};
+
+		#[extrinsic_call]
+		_(origin, 2, Some(fee));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_proof_system() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, H256(SP1_VERIFICATION_KEY), ProofSystem::PlonkSp1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_via_receipt_fungible_token() -> Result<(), BenchmarkError> {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let (receipts_root, branch, receipt_proof) = set_message_receipt::<T>(slot, &message);
+
+		let account = T::AccountId::from(ACCOUNT1);
+		let pot = T::AccountId::from(T::PalletId::get().into_account_truncating());
+		T::Currency::make_free_balance_be(&pot, BalanceOf::<T>::max_value() / 2u32.into());
+		TransferCaps::<T>::set(
+			(2, TransferDirection::Inbound, H256::zero()),
+			Some(u128::MAX),
+		);
+		ExecutionFees::<T>::insert(
+			2,
+			ExecutionFee {
+				fungible_token: Permill::from_percent(1),
+			},
+		);
+		let origin = RawOrigin::Signed(account);
+
+		#[extrinsic_call]
+		execute_via_receipt(
+			origin,
+			slot,
+			message,
+			receipts_root,
+			branch,
+			0,
+			0,
+			receipt_proof,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_via_receipt_arbitrary_message(
+		l: Linear<0, BOUNDED_DATA_MAX_LENGTH>,
+	) -> Result<(), BenchmarkError> {
+		let slot = 5085118;
+		let mut message = get_valid_amb_message();
+		message.message =
+			Message::ArbitraryMessage(BoundedVec::truncate_from(vec![1u8; l as usize]));
+		let (receipts_root, branch, receipt_proof) = set_message_receipt::<T>(slot, &message);
+
+		let account = T::AccountId::from(ACCOUNT1);
+		let origin = RawOrigin::Signed(account);
+
+		#[extrinsic_call]
+		execute_via_receipt(
+			origin,
+			slot,
+			message,
+			receipts_root,
+			branch,
+			0,
+			0,
+			receipt_proof,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_broadcaster_log_topic() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, 2, Some(MESSAGE_LOG_TOPIC));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_updater() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let max = T::MaxUpdaters::get();
+		let updaters = (1..max)
+			.map(|i| H256::from_low_u64_be(i.into()))
+			.collect::<Vec<_>>();
+		Updaters::<T>::put(BoundedVec::defensive_truncate_from(updaters));
+
+		#[extrinsic_call]
+		_(origin, H256(ACCOUNT1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_updater() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let max = T::MaxUpdaters::get();
+		let updaters = (1..max)
+			.map(|i| H256::from_low_u64_be(i.into()))
+			.chain([H256(ACCOUNT1)]);
+		Updaters::<T>::put(BoundedVec::defensive_truncate_from(
+			updaters.collect::<Vec<_>>(),
+		));
+
+		#[extrinsic_call]
+		_(origin, H256(ACCOUNT1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_updater_selection() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, UpdaterSelection::RoundRobin { turn_length: 100 });
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn apply_config_change() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		set_max_pending_config_change::<T>(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(PendingConfigChanges::<T>::get(0).is_none());
+		assert_eq!(WhitelistedDomains::<T>::get().len(), 10_000);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_config_change() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		set_max_pending_config_change::<T>(0);
+
+		#[extrinsic_call]
+		_(origin, 0);
+
+		assert!(PendingConfigChanges::<T>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
+/// Announces the largest configuration change, whitelisting 10_000 domains, as `id`.
+fn set_max_pending_config_change<T: Config>(id: u32) {
+	let domains = (0..10_000).collect::<Vec<u32>>();
+	let change = ConfigChange::WhitelistedDomains(BoundedVec::defensive_truncate_from(domains));
+	let applicable_at = frame_system::Pallet::<T>::block_number().saturated_into::<u32>();
+	PendingConfigChanges::<T>::insert(
+		id,
+		PendingConfigChange {
+			change,
+			applicable_at,
+		},
+	);
+}
+
+/// Stores the broadcaster, its log topic and a header proving a receipt with the log of `message`
+/// at the transaction and log index 0, returning the receipt proofs.
+fn set_message_receipt<T: Config>(
+	slot: u64,
+	message: &AddressedMessage,
+) -> (H256, ReceiptsRootBranch, ValidProof) {
+	let broadcaster = H256(hex!(
+		"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+	));
+	Broadcasters::<T>::set(2, broadcaster);
+	BroadcasterLogTopics::<T>::set(2, Some(MESSAGE_LOG_TOPIC));
+
+	let message_root = H256(keccak_256(&message.clone().abi_encode()));
+	let mut log = RlpStream::new_list(3);
+	log.append(&broadcaster[..20].to_vec());
+	log.begin_list(2);
+	log.append(&MESSAGE_LOG_TOPIC.as_bytes().to_vec());
+	log.append(&message_root.as_bytes().to_vec());
+	log.append(&Vec::<u8>::new());
+
+	let mut receipt = RlpStream::new_list(4);
+	receipt
+		.append(&1u8)
+		.append(&21_000u64)
+		.append(&vec![0u8; 256]);
+	receipt.begin_list(1).append_raw(&log.out(), 1);
+	// EIP-1559 receipts are prefixed with their transaction type.
+	let receipt = [vec![2u8], receipt.out().to_vec()].concat();
+
+	// A trie holding a single receipt is a leaf with the whole, hex prefixed, key.
+	let mut path = vec![0x20];
+	path.extend_from_slice(&rlp::encode(&0u32));
+	let mut leaf = RlpStream::new_list(2);
+	leaf.append(&path).append(&receipt);
+	let leaf = leaf.out().to_vec();
+	let receipts_root = H256(keccak_256(&leaf));
+
+	let branch: Vec<H256> = (0..12).map(H256::repeat_byte).collect();
+	let mut header = receipts_root;
+	let mut index = RECEIPTS_ROOT_GINDEX;
+	for sibling in &branch {
+		header = if index % 2 == 1 {
+			H256(sha2_256(&[sibling.as_bytes(), header.as_bytes()].concat()))
+		} else {
+			H256(sha2_256(&[header.as_bytes(), sibling.as_bytes()].concat()))
+		};
+		index /= 2;
+	}
+	Headers::<T>::set(slot, header);
+
+	(
+		receipts_root,
+		BoundedVec::truncate_from(branch),
+		BoundedVec::truncate_from(vec![BoundedVec::truncate_from(leaf)]),
+	)
+}
+
+fn bridged_asset_id<T: Config>() -> AssetIdOf<T> {
+	AssetIdOf::<T>::decode(&mut TrailingZeroInput::zeroes()).expect("Infinite input; qed")
+}
+
 pub fn get_valid_message() -> AddressedMessage {
 	let asset_id = H256::zero();
 	let amount = 1_000_000_000_000_000_000u128;
@@ -3,15 +3,17 @@
 
 use crate::{storage_utils::MessageStatusEnum, verifier::Verifier};
 use alloy_sol_types::{sol, SolValue};
-use ark_std::format;
 use avail_base::{MemoryTemporaryStorage, ProvidePostInherent};
 use avail_core::data_proof::{tx_uid, AddressedMessage, Message, MessageType};
-use sp1_verifier::{Groth16Verifier, GROTH16_VK_BYTES};
 
 use codec::Compact;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, UnixTime},
+	traits::{
+		fungibles,
+		tokens::{Fortitude, Precision},
+		Currency, ExistenceRequirement, UnixTime,
+	},
 	PalletId,
 };
 use sp_core::H256;
@@ -21,6 +23,7 @@ use sp_std::{vec, vec::Vec};
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod constants;
+mod message_handler;
 #[cfg(test)]
 mod mock;
 mod state;
@@ -30,7 +33,10 @@ mod tests;
 mod verifier;
 mod weights;
 
+pub use message_handler::MessageHandler;
 pub use pallet::*;
+pub use state::{split_message_id, Configuration, OutboundMessage, OutboundMessageStatus};
+pub use storage_utils::MessageStatusEnum;
 
 sol! {
 	#[derive(Debug)]
@@ -53,23 +59,35 @@ pub type FunctionInput = BoundedVec<u8, ConstU32<256>>;
 pub type FunctionOutput = BoundedVec<u8, ConstU32<512>>;
 pub type FunctionProof = BoundedVec<u8, ConstU32<1048>>;
 pub type ValidProof = BoundedVec<BoundedVec<u8, ConstU32<2048>>, ConstU32<32>>;
+pub type MessageBatch = BoundedVec<(AddressedMessage, ValidProof), ConstU32<32>>;
+pub type ReceiptsRootBranch = BoundedVec<H256, ConstU32<16>>;
 
-// Avail asset is supported for now
+// Avail asset is always supported, other assets have to be registered.
 pub const SUPPORTED_ASSET_ID: H256 = H256::zero();
 pub const FAILED_SEND_MSG_ID: &[u8] = b"vector:failed_send_msg_txs";
+pub const FAILED_SEND_MSG_ERRORS_ID: &[u8] = b"vector:failed_send_msg_errors";
 pub const LOG_TARGET: &str = "runtime::vector";
 pub const ROTATE_POSEIDON_OUTPUT_LENGTH: u32 = 32;
 pub const STEP_OUTPUT_LENGTH: u32 = 74;
+// Generalized index of the execution payload receipts root in a beacon block header, since Deneb.
+pub const RECEIPTS_ROOT_GINDEX: u64 = 6435;
+// Maximum number of announced configuration changes `on_initialize` visits in a block.
+pub const MAX_CONFIG_CHANGES_PER_BLOCK: u32 = 8;
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+pub type OutboundMessageOf<T> = OutboundMessage<<T as frame_system::Config>::AccountId>;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use ethabi::Token;
 	use ethabi::Token::Uint;
 	use frame_support::dispatch::GetDispatchInfo;
+	use frame_support::storage::with_storage_layer;
 	use frame_support::traits::{LockableCurrency, UnfilteredDispatchable};
+	use frame_support::weights::WeightMeter;
 	use frame_support::{pallet_prelude::ValueQuery, DefaultNoBound};
 	use frame_system::pallet_prelude::*;
 	use primitive_types::H160;
@@ -79,11 +97,16 @@ pub mod pallet {
 	use sp_runtime::traits::AccountIdConversion;
 	pub use weights::WeightInfo;
 
-	use crate::state::Configuration;
 	use crate::state::{
 		parse_rotate_output, parse_step_output, VerifiedRotate, VerifiedStep, VerifiedStepOutput,
 	};
-	use crate::storage_utils::{get_storage_root, get_storage_value};
+	use crate::state::{
+		ConfigChange, Configuration, ExecutionFee, PendingConfigChange, ProofSystem,
+		TransferDirection, UpdaterSelection,
+	};
+	use crate::storage_utils::{
+		get_receipt_log, get_storage_root, get_storage_value, is_valid_merkle_branch,
+	};
 	use crate::verifier::encode_packed;
 
 	use super::*;
@@ -140,7 +163,42 @@ pub mod pallet {
 		/// Emit when start sync committee does not match.
 		SyncCommitteeStartMismatch,
 		/// Mock is not enabled.
+		#[cfg(any(test, feature = "vector-mock"))]
 		MockIsNotEnabled,
+		/// Given token id is already mapped to an asset.
+		AssetAlreadyRegistered,
+		/// The light client state of the given slot was pruned.
+		SlotPruned,
+		/// The given slot or period is still within the retention window.
+		StateWithinRetention,
+		/// Transfers of the given domain and direction are paused.
+		TransfersPaused,
+		/// The transfer exceeds the cap of the current period.
+		TransferCapExceeded,
+		/// The message handler consumed more than the maximum message handler weight.
+		MessageHandlerWeightExceeded,
+		/// The log topic of the broadcaster is not set.
+		BroadcasterLogTopicNotSet,
+		/// The receipts root is not part of the header of the given slot.
+		InvalidReceiptsRoot,
+		/// The receipt log cannot be read from the given proof.
+		CannotGetReceiptLog,
+		/// The receipt log is not emitted by the broadcaster for the given message.
+		InvalidMessageLog,
+		/// The updater is already authorized.
+		UpdaterAlreadyAuthorized,
+		/// The updater is not one of the added updaters.
+		UpdaterNotFound,
+		/// The maximum number of updaters is reached.
+		TooManyUpdaters,
+		/// Round-robin turns must last at least one block.
+		InvalidUpdaterTurnLength,
+		/// The updater is authorized, but it is not its turn to submit updates.
+		NotUpdaterTurn,
+		/// No configuration change is announced with the given id.
+		ConfigChangeNotFound,
+		/// The delay of the announced configuration change has not passed yet.
+		ConfigChangeNotApplicable,
 	}
 
 	#[pallet::event]
@@ -173,6 +231,12 @@ pub mod pallet {
 			destination_domain: u32,
 			message_id: u64,
 		},
+		/// Emit when a `send_message` transaction fails, its funds are not moved.
+		MessageSendFailed {
+			who: T::AccountId,
+			tx_index: u32,
+			error: DispatchError,
+		},
 		/// Emit whitelisted domains that are updated.
 		WhitelistedDomainsUpdated,
 		/// Emit when configuration is updated.
@@ -197,7 +261,80 @@ pub mod pallet {
 		/// Emit when new sync committee is updated.
 		SyncCommitteeHashUpdated { period: u64, hash: H256 },
 		/// Emit when mocks are enabled or disabled
+		#[cfg(any(test, feature = "vector-mock"))]
 		MockEnabled { value: bool },
+		/// Emit when an Ethereum token is mapped to a local asset.
+		AssetRegistered {
+			token_id: H256,
+			asset_id: AssetIdOf<T>,
+		},
+		/// Emit when an Ethereum token mapping is removed.
+		AssetDeregistered {
+			token_id: H256,
+			asset_id: AssetIdOf<T>,
+		},
+		/// Emit when light client state is pruned manually.
+		LightClientStatePruned { slots: u32, periods: u32 },
+		/// Emit when a batch of messages is processed, with the batch indices of the executed and failed messages.
+		BatchExecuted {
+			slot: u64,
+			executed: Vec<u32>,
+			failed: Vec<u32>,
+		},
+		/// Emit when the transfer cap of a domain, direction and token is set or removed.
+		TransferCapSet {
+			domain: u32,
+			direction: TransferDirection,
+			token_id: H256,
+			cap: Option<u128>,
+		},
+		/// Emit when a transfer hits the cap and transfers of a domain, direction and token are paused.
+		TransfersPaused {
+			domain: u32,
+			direction: TransferDirection,
+			token_id: H256,
+		},
+		/// Emit when the execution fees of a domain are set or removed.
+		ExecutionFeeSet {
+			domain: u32,
+			fee: Option<ExecutionFee>,
+		},
+		/// Emit when the relayer that executed a message is paid.
+		ExecutionFeePaid {
+			message_id: u64,
+			relayer: T::AccountId,
+			fee: u128,
+		},
+		/// Emit when transfers of a domain, direction and token are resumed.
+		TransfersResumed {
+			domain: u32,
+			direction: TransferDirection,
+			token_id: H256,
+		},
+		/// Emit when the proof system of an SP1 verification key is set.
+		ProofSystemSet { vk: H256, system: ProofSystem },
+		/// Emit when the log topic of a broadcaster is set or removed.
+		BroadcasterLogTopicSet { domain: u32, topic: Option<H256> },
+		/// Emit when an updater is authorized besides `Updater`.
+		UpdaterAdded { updater: H256 },
+		/// Emit when an added updater is no longer authorized.
+		UpdaterRemoved { updater: H256 },
+		/// Emit when the way updaters take turns is changed.
+		UpdaterSelectionSet { selection: UpdaterSelection },
+		/// Emit every `UpdaterLivenessPeriod` blocks while the light client is not updated.
+		UpdatesStalled { last_update: u32 },
+		/// Emit when a configuration change is announced, before it can be applied.
+		ConfigChangeAnnounced {
+			id: u32,
+			change: ConfigChange,
+			applicable_at: u32,
+		},
+		/// Emit when an announced configuration change is applied.
+		ConfigChangeApplied { id: u32 },
+		/// Emit when an announced configuration change is cancelled.
+		ConfigChangeCancelled { id: u32 },
+		/// Emit when an announced configuration change can no longer be applied and is dropped.
+		ConfigChangeFailed { id: u32, error: DispatchError },
 	}
 
 	/// Storage for a head updates.
@@ -289,6 +426,19 @@ pub mod pallet {
 	#[pallet::getter(fn updater)]
 	pub type Updater<T: Config> = StorageValue<_, H256, ValueQuery>;
 
+	/// Updaters that can submit updates besides `Updater`, in the order they were added.
+	#[pallet::storage]
+	#[pallet::getter(fn updaters)]
+	pub type Updaters<T: Config> = StorageValue<_, BoundedVec<H256, T::MaxUpdaters>, ValueQuery>;
+
+	/// How the updaters take turns to submit updates.
+	#[pallet::storage]
+	pub type UpdaterSelectionStorage<T> = StorageValue<_, UpdaterSelection, ValueQuery>;
+
+	/// The block of the last light client head update.
+	#[pallet::storage]
+	pub type LastUpdateBlock<T> = StorageValue<_, u32, OptionQuery>;
+
 	/// Maps from a period to the the sync committee hash.
 	#[pallet::storage]
 	#[pallet::getter(fn sync_committee_hashes)]
@@ -299,11 +449,121 @@ pub mod pallet {
 	#[pallet::getter(fn sp1_verification_key)]
 	pub type SP1VerificationKey<T: Config> = StorageValue<_, H256, ValueQuery>;
 
+	/// Maps from an SP1 program verification key to the proof system its proofs are verified with.
+	#[pallet::storage]
+	#[pallet::getter(fn proof_systems)]
+	pub type ProofSystems<T> = StorageMap<_, Identity, H256, ProofSystem, ValueQuery>;
+
 	/// Enable mock functions
+	#[cfg(any(test, feature = "vector-mock"))]
 	#[pallet::storage]
 	#[pallet::getter(fn verification_disabled)]
 	pub type MockEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// Maps an Ethereum token id to the local asset minted and burned by the bridge.
+	#[pallet::storage]
+	#[pallet::getter(fn registered_assets)]
+	pub type RegisteredAssets<T: Config> = StorageMap<_, Identity, H256, AssetIdOf<T>>;
+
+	/// Fulfilled slots in the order they were fulfilled, used to prune their light client state.
+	#[pallet::storage]
+	pub type FulfilledSlots<T> = StorageMap<_, Identity, u64, u64>;
+
+	/// The index of the oldest and the next entry of `FulfilledSlots`.
+	#[pallet::storage]
+	pub type FulfilledSlotsBounds<T> = StorageValue<_, (u64, u64), ValueQuery>;
+
+	/// Maps from a message id to the position of the sent message in the outbound log and the message.
+	#[pallet::storage]
+	pub type OutboundMessages<T: Config> =
+		StorageMap<_, Identity, u64, (u64, OutboundMessageOf<T>)>;
+
+	/// Maps from a position in the outbound log to the id of the message logged there.
+	#[pallet::storage]
+	pub type OutboundMessageIds<T> = StorageMap<_, Identity, u64, u64>;
+
+	/// The index of the oldest and the next entry of `OutboundMessageIds`.
+	#[pallet::storage]
+	pub type OutboundMessageIdsBounds<T> = StorageValue<_, (u64, u64), ValueQuery>;
+
+	/// Slots below this one were pruned by `on_idle` and cannot be used to execute messages.
+	#[pallet::storage]
+	#[pallet::getter(fn pruned_slots_below)]
+	pub type PrunedSlotsBelow<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// The next period whose sync committee state will be pruned.
+	#[pallet::storage]
+	pub type PeriodPruningCursor<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Maximum amount of a token a domain can transfer in a direction per `TransferCapPeriod`.
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_caps)]
+	pub type TransferCaps<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, TransferDirection>,
+			NMapKey<Identity, H256>,
+		),
+		u128,
+	>;
+
+	/// The capping period and the amount transferred in it, per domain, direction and token.
+	#[pallet::storage]
+	pub type TransferUsage<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, TransferDirection>,
+			NMapKey<Identity, H256>,
+		),
+		(u32, u128),
+		ValueQuery,
+	>;
+
+	/// Maps from a domain to the topic of the logs its broadcaster emits for the sent messages.
+	#[pallet::storage]
+	#[pallet::getter(fn broadcaster_log_topics)]
+	pub type BroadcasterLogTopics<T> = StorageMap<_, Identity, u32, H256, OptionQuery>;
+
+	/// Fees paid to the relayers that execute the messages of a domain.
+	#[pallet::storage]
+	#[pallet::getter(fn execution_fees)]
+	pub type ExecutionFees<T> = StorageMap<_, Identity, u32, ExecutionFee, ValueQuery>;
+
+	/// Transaction indices of the `send_message` calls that failed in the current block.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type FailedSendMessageTxs<T> = StorageValue<_, Vec<u32>, ValueQuery>;
+
+	/// Domains, directions and tokens whose transfers are paused until governance resumes them.
+	#[pallet::storage]
+	#[pallet::getter(fn paused_transfers)]
+	pub type PausedTransfers<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, u32>,
+			NMapKey<Identity, TransferDirection>,
+			NMapKey<Identity, H256>,
+		),
+		bool,
+		ValueQuery,
+	>;
+
+	/// Announced configuration changes waiting for `ConfigChangeDelay` to pass, by id.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_config_changes)]
+	pub type PendingConfigChanges<T> = StorageMap<_, Identity, u32, PendingConfigChange>;
+
+	/// The id of the next announced configuration change.
+	#[pallet::storage]
+	pub type NextConfigChangeId<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The id of the next announced configuration change `on_initialize` applies once its delay
+	/// has passed.
+	#[pallet::storage]
+	pub type NextConfigChangeToApply<T> = StorageValue<_, u32, ValueQuery>;
+
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
@@ -313,6 +573,7 @@ pub mod pallet {
 
 		parameter_types! {
 			pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+			pub const MaxMessageHandlerWeight: Weight = Weight::from_parts(1_000_000_000, 65_536);
 		}
 
 		/// Provides a viable default config that can be used with
@@ -329,6 +590,15 @@ pub mod pallet {
 			type WeightInfo = ();
 			type MessageMappingStorageIndex = ConstU64<1>;
 			type AvailDomain = ConstU32<1>;
+			type SlotRetention = ConstU64<8192>;
+			type PeriodRetention = ConstU64<4>;
+			type TransferCapPeriod = ConstU32<7200>;
+			type OutboundMessageRetention = ConstU32<50_400>;
+			type MaxUpdaters = ConstU32<8>;
+			type UpdaterLivenessPeriod = ConstU32<600>;
+			type ConfigChangeDelay = ConstU32<0>;
+			type MessageHandler = ();
+			type MaxMessageHandlerWeight = MaxMessageHandlerWeight;
 			#[inject_runtime_type]
 			type RuntimeEvent = ();
 			#[inject_runtime_type]
@@ -355,6 +625,9 @@ pub mod pallet {
 		/// Dependency that can provide current time.
 		#[pallet::no_default]
 		type TimeProvider: UnixTime;
+		/// Registry of the non-AVAIL assets that can be bridged.
+		#[pallet::no_default]
+		type Assets: fungibles::Mutate<Self::AccountId>;
 		/// The index of the `messages` mapping in contract.
 		/// This is mandatory when calling execute messages via storage proofs.
 		#[pallet::constant]
@@ -365,6 +638,33 @@ pub mod pallet {
 		/// Unique value associated with Avail Network. Used to distinguish messages between Avail and non-Avail networks.
 		#[pallet::constant]
 		type AvailDomain: Get<u32>;
+		/// Number of slots behind the head whose headers, timestamps and execution state roots are kept.
+		#[pallet::constant]
+		type SlotRetention: Get<u64>;
+		/// Number of periods behind the current one whose sync committee poseidons and hashes are kept.
+		#[pallet::constant]
+		type PeriodRetention: Get<u64>;
+		/// Number of blocks over which the transfer caps are accounted.
+		#[pallet::constant]
+		type TransferCapPeriod: Get<u32>;
+		/// Number of blocks the messages sent from Avail are kept in the outbound message log.
+		#[pallet::constant]
+		type OutboundMessageRetention: Get<u32>;
+		/// Maximum number of updaters authorized besides `Updater`.
+		#[pallet::constant]
+		type MaxUpdaters: Get<u32>;
+		/// Number of blocks without a head update after which `UpdatesStalled` is emitted.
+		#[pallet::constant]
+		type UpdaterLivenessPeriod: Get<u32>;
+		/// Number of blocks between the announcement of a sensitive configuration change and the
+		/// block from which it can be applied. Changes are applied right away when it is zero.
+		#[pallet::constant]
+		type ConfigChangeDelay: Get<u32>;
+		/// Handlers of the arbitrary messages executed by the bridge.
+		type MessageHandler: MessageHandler;
+		/// Maximum weight a message handler can consume for a single message.
+		#[pallet::constant]
+		type MaxMessageHandlerWeight: Get<Weight>;
 	}
 
 	#[pallet::genesis_config]
@@ -435,14 +735,30 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			if let Some(failed_txs) =
 				MemoryTemporaryStorage::take::<Vec<Compact<u32>>>(FAILED_SEND_MSG_ID)
 			{
 				log::trace!(target: LOG_TARGET, "Failed Txs cleaned: {failed_txs:?}");
 			}
+			MemoryTemporaryStorage::remove(FAILED_SEND_MSG_ERRORS_ID);
+			FailedSendMessageTxs::<T>::kill();
+			let liveness_weight = Self::check_updates_liveness(n.saturated_into());
+			let config_weight = Self::apply_due_config_changes(n.saturated_into());
+
+			T::DbWeight::get()
+				.writes(1)
+				.saturating_add(liveness_weight)
+				.saturating_add(config_weight)
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			Self::prune_slots(&mut meter);
+			Self::prune_periods(&mut meter);
+			Self::prune_outbound_messages(&mut meter);
 
-			Weight::zero()
+			meter.consumed()
 		}
 	}
 
@@ -468,9 +784,7 @@ pub mod pallet {
 			#[pallet::compact] slot: u64,
 		) -> DispatchResultWithPostInfo {
 			let sender: [u8; 32] = ensure_signed(origin)?.into();
-			let updater = Updater::<T>::get();
-			// ensure sender is preconfigured
-			ensure!(H256(sender) == updater, Error::<T>::UpdaterMisMatch);
+			Self::ensure_updater(H256(sender))?;
 
 			let config = ConfigurationStorage::<T>::get();
 			let input_hash = H256(sha2_256(input.as_slice()));
@@ -518,13 +832,9 @@ pub mod pallet {
 		}
 
 		/// Executes message if a valid proofs are provided for the supported message type, assets and domains.
+		/// Arbitrary messages are dispatched to the `MessageHandler` accepting their sender, the unused handler weight is refunded.
 		#[pallet::call_index(1)]
-		#[pallet::weight({
-			match addr_message.message {
-				Message::ArbitraryMessage(ref data) => T::WeightInfo::execute_arbitrary_message(data.len() as u32),
-				Message::FungibleToken {..} => T::WeightInfo::execute_fungible_token(),
-			}
-		})]
+		#[pallet::weight(weight_helper::execute::<T>(&addr_message.message))]
 		pub fn execute(
 			origin: OriginFor<T>,
 			#[pallet::compact] slot: u64,
@@ -532,71 +842,35 @@ pub mod pallet {
 			account_proof: ValidProof,
 			storage_proof: ValidProof,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
+			let relayer = ensure_signed(origin)?;
+			let weight = weight_helper::execute::<T>(&addr_message.message);
 			let encoded_data = addr_message.clone().abi_encode();
 			let message_root = H256(keccak_256(encoded_data.as_slice()));
 
 			Self::check_preconditions(&addr_message, message_root)?;
 
-			ensure!(
-				!SourceChainFrozen::<T>::get(addr_message.origin_domain),
-				Error::<T>::SourceChainFrozen
-			);
-			let root = ExecutionStateRoots::<T>::get(slot);
-			let broadcaster = Broadcasters::<T>::get(addr_message.origin_domain);
-
-			// extract contract address
-			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
-			let account_proof_vec = account_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
-			let storage_root =
-				get_storage_root(account_proof_vec, contract_broadcaster_address, root)
-					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
-
-			let message_id = Uint(U256::from(addr_message.id));
-			let mm_idx = Uint(U256::from(T::MessageMappingStorageIndex::get()));
-			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
-
-			let storage_proof_vec = storage_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
-			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
-				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
-
-			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
-
-			if let Message::FungibleToken { asset_id, amount } = &addr_message.message {
-				ensure!(
-					SUPPORTED_ASSET_ID == *asset_id,
-					Error::<T>::AssetNotSupported
-				);
-
-				let destination_account_id =
-					T::AccountId::decode(&mut &addr_message.to.encode()[..])
-						.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
+			let origin_domain = addr_message.origin_domain;
+			let token_id = Self::capped_token_id(&addr_message.message);
+			let storage_root = Self::broadcaster_storage_root(slot, origin_domain, &account_proof)?;
+			let result = with_storage_layer(|| {
+				Self::execute_message(
+					&relayer,
+					addr_message,
+					message_root,
+					storage_root,
+					&storage_proof,
+				)
+			});
 
-				T::Currency::transfer(
-					&Self::account_id(),
-					&destination_account_id,
-					(*amount).saturated_into(),
-					ExistenceRequirement::AllowDeath,
-				)?;
+			// The message stays executable once transfers are resumed.
+			let cap_exceeded = matches!(result, Err(ref e) if Self::is_cap_exceeded(e));
+			if let Some(token_id) = token_id.filter(|_| cap_exceeded) {
+				Self::pause_transfers(origin_domain, TransferDirection::Inbound, token_id);
+				return Ok(().into());
 			}
+			let unused_weight = result?;
 
-			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
-			Self::deposit_event(Event::<T>::MessageExecuted {
-				from: addr_message.from,
-				to: addr_message.to,
-				message_id: addr_message.id,
-				message_root,
-			});
-
-			Ok(().into())
+			Ok(Some(weight.saturating_sub(unused_weight)).into())
 		}
 
 		/// source_chain_froze froze source chain and prevent messages to be executed.
@@ -641,22 +915,48 @@ pub mod pallet {
 			#[pallet::compact] domain: u32,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-
-			let dispatch = Self::do_send_message(who, message, to, domain);
-			if dispatch.is_err() {
+			let message_type = message.r#type();
+			let token_id = Self::capped_token_id(&message);
+
+			// Funds locked before a failure are rolled back even if the call itself succeeds.
+			let dispatch =
+				with_storage_layer(|| Self::do_send_message(who.clone(), message, to, domain));
+			if let Err(e) = &dispatch {
+				let tx_idx_result = <frame_system::Pallet<T>>::extrinsic_index();
+				// this should never happen and we can just log warn
+				if tx_idx_result.is_none() {
+					log::warn!(target: LOG_TARGET, "Transaction index is none!");
+				}
+				let tx_idx = tx_idx_result.unwrap_or_default();
 				let _ = MemoryTemporaryStorage::update::<Vec<Compact<u32>>, _>(
 					FAILED_SEND_MSG_ID.to_vec(),
 					|failed| {
-						let tx_idx_result = <frame_system::Pallet<T>>::extrinsic_index();
-						// this should never happen and we can just log warn
-						if tx_idx_result.is_none() {
-							log::warn!(target: LOG_TARGET, "Transaction index is none!");
-						}
-						let tx_idx = tx_idx_result.unwrap_or_default();
 						failed.push(tx_idx.into());
 						log::trace!(target: LOG_TARGET, "Send Message failed txs: {failed:?}");
 					},
 				);
+				// The failure is reported and logged by `failed_send_message_txs`, as events and
+				// storage changes of failed calls are discarded.
+				let failed = OutboundMessage {
+					from: who,
+					to,
+					destination_domain: domain,
+					message_type,
+					block_number: <frame_system::Pallet<T>>::block_number().saturated_into(),
+					status: OutboundMessageStatus::Failed,
+				};
+				let _ = MemoryTemporaryStorage::update::<
+					Vec<(u32, OutboundMessageOf<T>, DispatchError)>,
+					_,
+				>(FAILED_SEND_MSG_ERRORS_ID.to_vec(), |errors| {
+					errors.push((tx_idx, failed, e.error))
+				});
+
+				// The message is not sent, but the pause must outlive the failed transfer.
+				if let Some(token_id) = token_id.filter(|_| Self::is_cap_exceeded(&e.error)) {
+					Self::pause_transfers(domain, TransferDirection::Outbound, token_id);
+					return Ok(().into());
+				}
 			}
 
 			dispatch
@@ -688,8 +988,10 @@ pub mod pallet {
 		}
 
 		/// set_broadcaster sets the broadcaster address of the message from the origin chain.
+		/// The change is announced and applied after `ConfigChangeDelay`.
 		//
-		// Test names: set_broadcaster_works_with_root(), set_broadcaster_does_not_work_with_non_root()
+		// Test names: set_broadcaster_works_with_root(), set_broadcaster_does_not_work_with_non_root(),
+		//	set_broadcaster_is_announced_with_delay()
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::set_broadcaster())]
 		pub fn set_broadcaster(
@@ -698,21 +1000,15 @@ pub mod pallet {
 			broadcaster: H256,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			let old_bc = Broadcasters::<T>::get(broadcaster_domain);
-
-			Broadcasters::<T>::set(broadcaster_domain, broadcaster);
-
-			Self::deposit_event(Event::BroadcasterUpdated {
-				old: old_bc,
-				new: broadcaster,
+			Self::announce_config_change(ConfigChange::Broadcaster {
 				domain: broadcaster_domain,
-			});
-
-			Ok(())
+				broadcaster,
+			})
 		}
 
 		/// The set_whitelisted_domains function allows the root (administrator) to set the whitelisted domains. It is a
 		/// privileged function intended for administrative purposes, used to manage a list of permitted domains.
+		/// The change is announced and applied after `ConfigChangeDelay`.
 		//
 		// Test names: set_whitelisted_domains_works_with_root(), set_whitelisted_domains_does_not_work_with_non_root()
 		#[pallet::call_index(6)]
@@ -722,11 +1018,7 @@ pub mod pallet {
 			value: BoundedVec<u32, ConstU32<10_000>>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			WhitelistedDomains::<T>::put(value);
-
-			Self::deposit_event(Event::WhitelistedDomainsUpdated);
-
-			Ok(())
+			Self::announce_config_change(ConfigChange::WhitelistedDomains(value))
 		}
 
 		/// The set_configuration function allows the root (administrator) to set the configuration. It is a
@@ -747,6 +1039,7 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Sets the step and rotate function ids, once the change is applied after `ConfigChangeDelay`.
 		#[pallet::call_index(8)]
 		#[pallet::weight(T::WeightInfo::set_function_ids())]
 		pub fn set_function_ids(
@@ -754,13 +1047,10 @@ pub mod pallet {
 			value: Option<(H256, H256)>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			FunctionIds::<T>::put(value);
-
-			Self::deposit_event(Event::FunctionIdsUpdated { value });
-
-			Ok(())
+			Self::announce_config_change(ConfigChange::FunctionIds(value))
 		}
 
+		/// Sets the step verification key, once the change is applied after `ConfigChangeDelay`.
 		#[pallet::call_index(9)]
 		#[pallet::weight(T::WeightInfo::set_step_verification_key())]
 		pub fn set_step_verification_key(
@@ -773,13 +1063,10 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
 			}
 
-			StepVerificationKey::<T>::put(value.clone());
-
-			Self::deposit_event(Event::StepVerificationKeyUpdated { value });
-
-			Ok(())
+			Self::announce_config_change(ConfigChange::StepVerificationKey(value))
 		}
 
+		/// Sets the rotate verification key, once the change is applied after `ConfigChangeDelay`.
 		#[pallet::call_index(10)]
 		#[pallet::weight(T::WeightInfo::set_rotate_verification_key())]
 		pub fn set_rotate_verification_key(
@@ -792,16 +1079,16 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::MalformedVerificationKey)?;
 			}
 
-			RotateVerificationKey::<T>::put(value.clone());
-
-			Self::deposit_event(Event::RotateVerificationKeyUpdated { value });
-
-			Ok(())
+			Self::announce_config_change(ConfigChange::RotateVerificationKey(value))
 		}
 
 		#[pallet::call_index(11)]
 		#[pallet::weight((
-			T::WeightInfo::failed_tx_index(0u32),
+			T::WeightInfo::failed_tx_index(failed_txs.len() as u32)
+				.saturating_add(T::DbWeight::get().writes(1))
+				// Logs the failed messages.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::DbWeight::get().writes(2).saturating_mul(failed_txs.len() as u64)),
 			DispatchClass::Mandatory
 		))]
 		pub fn failed_send_message_txs(
@@ -817,18 +1104,21 @@ pub mod pallet {
 				Error::<T>::InvalidFailedIndices
 			);
 
+			let errors = MemoryTemporaryStorage::take::<
+				Vec<(u32, OutboundMessageOf<T>, DispatchError)>,
+			>(FAILED_SEND_MSG_ERRORS_ID)
+			.unwrap_or_default();
+			Self::report_failed_send_messages(failed_txs, errors);
+
 			Ok(())
 		}
 
+		/// Sets the primary updater, once the change is applied after `ConfigChangeDelay`.
 		#[pallet::call_index(12)]
 		#[pallet::weight(T::WeightInfo::set_updater())]
 		pub fn set_updater(origin: OriginFor<T>, updater: H256) -> DispatchResult {
 			ensure_root(origin)?;
-			let old = Updater::<T>::get();
-			Updater::<T>::set(updater);
-
-			Self::deposit_event(Event::<T>::NewUpdater { old, new: updater });
-			Ok(())
+			Self::announce_config_change(ConfigChange::Updater(updater))
 		}
 
 		/// The entrypoint for fulfill a call.
@@ -842,9 +1132,7 @@ pub mod pallet {
 			public_values: PublicValuesInput,
 		) -> DispatchResultWithPostInfo {
 			let sender: [u8; 32] = ensure_signed(origin)?.into();
-			let updater = Updater::<T>::get();
-			// ensure sender is preconfigured
-			ensure!(H256(sender) == updater, Error::<T>::UpdaterMisMatch);
+			Self::ensure_updater(H256(sender))?;
 
 			let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_values, true)
 				.map_err(|_| Error::<T>::CannotDecodePublicValue)?;
@@ -866,12 +1154,7 @@ pub mod pallet {
 			);
 
 			let sp1_vk = SP1VerificationKey::<T>::get();
-			let is_valid = Groth16Verifier::verify(
-				&proof,
-				&public_values,
-				&format!("{:?}", sp1_vk),
-				&GROTH16_VK_BYTES,
-			);
+			let is_valid = ProofSystems::<T>::get(sp1_vk).verify(&proof, &public_values, sp1_vk);
 			ensure!(is_valid.is_ok(), Error::<T>::VerificationFailed);
 
 			Head::<T>::set(new_head);
@@ -889,6 +1172,7 @@ pub mod pallet {
 
 			Headers::<T>::insert(new_head, new_header);
 			ExecutionStateRoots::<T>::insert(new_head, new_execution_state_root);
+			Self::track_fulfilled_slot(new_head);
 
 			Self::deposit_event(Event::HeadUpdated {
 				slot: new_head,
@@ -935,19 +1219,12 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Sets the SP1 verification key, once the change is applied after `ConfigChangeDelay`.
 		#[pallet::call_index(14)]
 		#[pallet::weight(T::WeightInfo::set_sp1_verification_key())]
 		pub fn set_sp1_verification_key(origin: OriginFor<T>, sp1_vk: H256) -> DispatchResult {
 			ensure_root(origin)?;
-			let old_vk = SP1VerificationKey::<T>::get();
-			SP1VerificationKey::<T>::put(sp1_vk);
-
-			Self::deposit_event(Event::NewSP1VerificationKey {
-				old: old_vk,
-				new: sp1_vk,
-			});
-
-			Ok(())
+			Self::announce_config_change(ConfigChange::SP1VerificationKey(sp1_vk))
 		}
 
 		#[pallet::call_index(15)]
@@ -965,19 +1242,20 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Enables or disables `mock_fulfill`, once the change is applied after `ConfigChangeDelay`.
+		/// Only compiled with the `vector-mock` feature.
+		#[cfg(any(test, feature = "vector-mock"))]
 		#[pallet::call_index(16)]
 		#[pallet::weight(T::WeightInfo::enable_mock())]
 		pub fn enable_mock(origin: OriginFor<T>, value: bool) -> DispatchResult {
 			ensure_root(origin)?;
-
-			MockEnabled::<T>::set(value);
-			Self::deposit_event(Event::MockEnabled { value });
-
-			Ok(())
+			Self::announce_config_change(ConfigChange::MockEnabled(value))
 		}
 
 		/// The entrypoint for mock_fulfill call.
 		/// public_values Input public values.
+		/// Only compiled with the `vector-mock` feature.
+		#[cfg(any(test, feature = "vector-mock"))]
 		#[pallet::call_index(17)]
 		#[pallet::weight(T::WeightInfo::fulfill())]
 		pub fn mock_fulfill(
@@ -990,9 +1268,7 @@ pub mod pallet {
 			);
 
 			let sender: [u8; 32] = ensure_signed(origin)?.into();
-			let updater = Updater::<T>::get();
-			// ensure sender is preconfigured
-			ensure!(H256(sender) == updater, Error::<T>::UpdaterMisMatch);
+			Self::ensure_updater(H256(sender))?;
 
 			let proof_outputs: ProofOutputs = SolValue::abi_decode(&public_values, true)
 				.map_err(|_| Error::<T>::CannotDecodePublicValue)?;
@@ -1028,6 +1304,7 @@ pub mod pallet {
 
 			Headers::<T>::insert(new_head, new_header);
 			ExecutionStateRoots::<T>::insert(new_head, new_execution_state_root);
+			Self::track_fulfilled_slot(new_head);
 
 			Self::deposit_event(Event::HeadUpdated {
 				slot: new_head,
@@ -1073,104 +1350,1155 @@ pub mod pallet {
 
 			Ok(().into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn do_send_message(
-			who: T::AccountId,
-			message: Message,
-			to: H256,
-			domain: u32,
-		) -> DispatchResultWithPostInfo {
-			// Ensure the domain is currently supported
+		/// Maps the Ethereum token `token_id` to the local asset `asset_id`, allowing it to be bridged.
+		/// The asset must be created beforehand and is minted and burned by the bridge.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::register_asset())]
+		pub fn register_asset(
+			origin: OriginFor<T>,
+			token_id: H256,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
 			ensure!(
-				Self::is_domain_valid(domain),
-				Error::<T>::DomainNotSupported
+				token_id != SUPPORTED_ASSET_ID && !RegisteredAssets::<T>::contains_key(token_id),
+				Error::<T>::AssetAlreadyRegistered
 			);
-			// Check MessageType and enforce the rules
-			let message_type = message.r#type();
-			match message {
-				Message::FungibleToken { asset_id, amount } => {
-					ensure!(
-						SUPPORTED_ASSET_ID == asset_id,
-						Error::<T>::AssetNotSupported
-					);
-					ensure!(
-						amount.saturated_into::<u128>() > 0,
-						Error::<T>::InvalidBridgeInputs
-					);
-					T::Currency::transfer(
-						&who,
-						&Self::account_id(),
-						amount.saturated_into(),
-						ExistenceRequirement::KeepAlive,
-					)?;
-				},
-				Message::ArbitraryMessage(data) => {
-					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
-				},
-			};
-
-			let message_id = Self::fetch_curr_message_id()?;
 
-			Self::deposit_event(Event::MessageSubmitted {
-				from: who,
-				to,
-				message_type,
-				destination_domain: domain,
-				message_id,
-			});
+			RegisteredAssets::<T>::insert(token_id, asset_id.clone());
+			Self::deposit_event(Event::AssetRegistered { token_id, asset_id });
 
-			Ok(().into())
+			Ok(())
 		}
 
-		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
-			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
-			let tx_index_option = <frame_system::Pallet<T>>::extrinsic_index();
+		/// Executes a batch of messages from the same origin domain, verifying the broadcaster `account_proof` only once.
+		/// Each message is executed independently: a failing message is skipped and its changes are reverted.
+		//
+		// Test names: execute_batch_works(), execute_batch_does_not_accept_empty_batch(),
+		//	execute_batch_fails_with_faulty_account_proof(), execute_batch_skips_messages_from_other_domains()
+		#[pallet::call_index(20)]
+		#[pallet::weight(weight_helper::execute_batch::<T>(messages))]
+		pub fn execute_batch(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			account_proof: ValidProof,
+			messages: MessageBatch,
+		) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+			let weight = weight_helper::execute_batch::<T>(&messages);
+			let origin_domain = messages
+				.first()
+				.map(|(addr_message, _)| addr_message.origin_domain)
+				.ok_or(Error::<T>::InvalidBridgeInputs)?;
+
+			let storage_root = Self::broadcaster_storage_root(slot, origin_domain, &account_proof)?;
+
+			let mut executed = Vec::new();
+			let mut failed = Vec::new();
+			let mut unused_weight = Weight::zero();
+			for (idx, (addr_message, storage_proof)) in messages.into_iter().enumerate() {
+				let token_id = Self::capped_token_id(&addr_message.message);
+				let result = with_storage_layer(|| -> Result<Weight, DispatchError> {
+					ensure!(
+						addr_message.origin_domain == origin_domain,
+						Error::<T>::UnsupportedOriginChain
+					);
+					let encoded_data = addr_message.clone().abi_encode();
+					let message_root = H256(keccak_256(encoded_data.as_slice()));
+
+					Self::check_preconditions(&addr_message, message_root)?;
+					Self::execute_message(
+						&relayer,
+						addr_message,
+						message_root,
+						storage_root,
+						&storage_proof,
+					)
+				});
 
-			match tx_index_option {
-				Some(tx_index) => Ok(tx_uid(number, tx_index)),
-				None => Err(Error::<T>::CurrentMessageIdNotFound.into()),
+				// The batch is bounded, so `idx` always fits in `u32`.
+				match result {
+					Ok(unused) => {
+						executed.push(idx as u32);
+						unused_weight.saturating_accrue(unused);
+					},
+					Err(e) => {
+						log::debug!(target: LOG_TARGET, "Batch message {idx} failed: {e:?}");
+						if let Some(token_id) = token_id.filter(|_| Self::is_cap_exceeded(&e)) {
+							Self::pause_transfers(
+								origin_domain,
+								TransferDirection::Inbound,
+								token_id,
+							);
+						}
+						failed.push(idx as u32);
+					},
+				}
 			}
+
+			Self::deposit_event(Event::BatchExecuted {
+				slot,
+				executed,
+				failed,
+			});
+
+			Ok(Some(weight.saturating_sub(unused_weight)).into())
 		}
 
-		fn check_preconditions(
-			message: &AddressedMessage,
-			message_root: H256,
-		) -> Result<(), DispatchError> {
-			let message_status = MessageStatus::<T>::get(message_root);
-			// Message must not be executed
+		/// Prunes the light client state of `slots` and `periods` outside of the retention window.
+		/// `on_idle` only prunes the slots fulfilled after the pruning was introduced, older ones are pruned with this call.
+		//
+		// Test names: prune_light_client_state_works_with_root(), prune_light_client_state_does_not_work_with_non_root(),
+		//	prune_light_client_state_keeps_retained_state()
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::prune_light_client_state(slots.len() as u32, periods.len() as u32))]
+		pub fn prune_light_client_state(
+			origin: OriginFor<T>,
+			slots: BoundedVec<u64, ConstU32<1024>>,
+			periods: BoundedVec<u64, ConstU32<1024>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
 			ensure!(
-				message_status == MessageStatusEnum::NotExecuted,
-				Error::<T>::MessageAlreadyExecuted
+				slots.iter().all(|slot| Self::is_slot_prunable(*slot)),
+				Error::<T>::StateWithinRetention
 			);
-
 			ensure!(
-				message.destination_domain == T::AvailDomain::get(),
-				Error::<T>::WrongDestinationChain
+				periods
+					.iter()
+					.all(|period| Self::is_period_prunable(*period)),
+				Error::<T>::StateWithinRetention
 			);
 
-			ensure!(
-				WhitelistedDomains::<T>::get().contains(&message.origin_domain),
-				Error::<T>::UnsupportedOriginChain
-			);
+			for slot in slots.iter() {
+				Self::remove_slot_state(*slot);
+			}
+			for period in periods.iter() {
+				Self::remove_period_state(*period);
+			}
 
-			let source_chain = Broadcasters::<T>::get(message.origin_domain);
-			ensure!(
-				source_chain != H256::zero(),
-				Error::<T>::BroadcasterSourceChainNotSet
-			);
+			Self::deposit_event(Event::LightClientStatePruned {
+				slots: slots.len() as u32,
+				periods: periods.len() as u32,
+			});
 
 			Ok(())
 		}
 
-		/// The account ID of the bridge's pot.
-		pub fn account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
-		}
+		/// Sets the maximum amount of `token_id` `domain` can transfer in `direction` per `TransferCapPeriod`.
+		/// When a transfer would exceed it, transfers of the domain, direction and token are paused. `None` removes the cap.
+		//
+		// Test names: set_transfer_cap_works_with_root(), set_transfer_cap_does_not_work_with_non_root()
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::set_transfer_cap())]
+		pub fn set_transfer_cap(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			direction: TransferDirection,
+			token_id: H256,
+			cap: Option<u128>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
 
-		fn rotate_into(
-			finalized_slot: u64,
+			TransferCaps::<T>::set((domain, direction, token_id), cap);
+			Self::deposit_event(Event::TransferCapSet {
+				domain,
+				direction,
+				token_id,
+				cap,
+			});
+
+			Ok(())
+		}
+
+		/// Resumes the paused transfers of `token_id` by `domain` in `direction` and resets their usage of the current period.
+		//
+		// Test names: resume_transfers_works_with_root(), resume_transfers_does_not_work_with_non_root()
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::resume_transfers())]
+		pub fn resume_transfers(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			direction: TransferDirection,
+			token_id: H256,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			PausedTransfers::<T>::remove((domain, direction, token_id));
+			TransferUsage::<T>::remove((domain, direction, token_id));
+			Self::deposit_event(Event::TransfersResumed {
+				domain,
+				direction,
+				token_id,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the fees paid to the relayers that execute the messages of `domain`. `None` removes them.
+		//
+		// Test names: set_execution_fee_works_with_root(), set_execution_fee_does_not_work_with_non_root()
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_execution_fee())]
+		pub fn set_execution_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			fee: Option<ExecutionFee>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			match fee {
+				Some(fee) => ExecutionFees::<T>::insert(domain, fee),
+				None => ExecutionFees::<T>::remove(domain),
+			}
+			Self::deposit_event(Event::ExecutionFeeSet { domain, fee });
+
+			Ok(())
+		}
+
+		/// Sets the proof system of the SP1 verification key `vk`, the keys without one use Groth16.
+		/// Setting it before `set_sp1_verification_key` migrates the prover without a runtime upgrade.
+		/// The change is announced and applied after `ConfigChangeDelay`.
+		//
+		// Test names: set_proof_system_works_with_root(), set_proof_system_does_not_work_with_non_root(),
+		//	test_fulfill_with_plonk_proof_system_rejects_groth16_proof()
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_proof_system())]
+		pub fn set_proof_system(
+			origin: OriginFor<T>,
+			vk: H256,
+			system: ProofSystem,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::announce_config_change(ConfigChange::ProofSystem { vk, system })
+		}
+
+		/// Executes a message proven by the log its broadcaster emitted, instead of its storage.
+		/// The receipts root is proven against the header of `slot`, the log must carry the broadcaster
+		/// log topic followed by the message root.
+		//
+		// Test names: execute_via_receipt_works(), execute_via_receipt_fails_with_invalid_receipts_root(),
+		//	execute_via_receipt_fails_with_foreign_log(), execute_via_receipt_fails_without_log_topic()
+		#[pallet::call_index(26)]
+		#[pallet::weight(weight_helper::execute_via_receipt::<T>(&addr_message.message))]
+		pub fn execute_via_receipt(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			addr_message: AddressedMessage,
+			receipts_root: H256,
+			receipts_root_branch: ReceiptsRootBranch,
+			#[pallet::compact] tx_index: u32,
+			#[pallet::compact] log_index: u32,
+			receipt_proof: ValidProof,
+		) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+			let weight = weight_helper::execute_via_receipt::<T>(&addr_message.message);
+			let encoded_data = addr_message.clone().abi_encode();
+			let message_root = H256(keccak_256(encoded_data.as_slice()));
+
+			Self::check_preconditions(&addr_message, message_root)?;
+
+			let origin_domain = addr_message.origin_domain;
+			let token_id = Self::capped_token_id(&addr_message.message);
+			Self::verify_receipts_root(slot, origin_domain, receipts_root, &receipts_root_branch)?;
+			Self::verify_message_log(
+				origin_domain,
+				message_root,
+				receipts_root,
+				tx_index,
+				log_index,
+				&receipt_proof,
+			)?;
+			let result =
+				with_storage_layer(|| Self::apply_message(&relayer, addr_message, message_root));
+
+			// The message stays executable once transfers are resumed.
+			let cap_exceeded = matches!(result, Err(ref e) if Self::is_cap_exceeded(e));
+			if let Some(token_id) = token_id.filter(|_| cap_exceeded) {
+				Self::pause_transfers(origin_domain, TransferDirection::Inbound, token_id);
+				return Ok(().into());
+			}
+			let unused_weight = result?;
+
+			Ok(Some(weight.saturating_sub(unused_weight)).into())
+		}
+
+		/// Sets the topic of the logs the broadcaster of `domain` emits for the sent messages. `None` removes it.
+		/// The change is announced and applied after `ConfigChangeDelay`.
+		//
+		// Test names: set_broadcaster_log_topic_works_with_root(), set_broadcaster_log_topic_does_not_work_with_non_root(),
+		//	set_broadcaster_log_topic_is_announced_with_delay()
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_broadcaster_log_topic())]
+		pub fn set_broadcaster_log_topic(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			topic: Option<H256>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::announce_config_change(ConfigChange::BroadcasterLogTopic { domain, topic })
+		}
+
+		/// Authorizes `updater` to submit updates besides `Updater`, once the change is applied after
+		/// `ConfigChangeDelay`.
+		//
+		// Test names: add_updater_works_with_root(), add_updater_does_not_work_with_non_root(),
+		//	add_updater_fails_when_already_authorized(), add_updater_fails_when_full(),
+		//	add_updater_is_announced_with_delay()
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::add_updater())]
+		pub fn add_updater(origin: OriginFor<T>, updater: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				!Self::authorized_updaters().contains(&updater),
+				Error::<T>::UpdaterAlreadyAuthorized
+			);
+			ensure!(
+				Updaters::<T>::decode_len().unwrap_or_default() < T::MaxUpdaters::get() as usize,
+				Error::<T>::TooManyUpdaters
+			);
+
+			Self::announce_config_change(ConfigChange::AddUpdater(updater))
+		}
+
+		/// Revokes an updater authorized with `add_updater`, once the change is applied after
+		/// `ConfigChangeDelay`.
+		//
+		// Test names: remove_updater_works_with_root(), remove_updater_does_not_work_with_non_root(),
+		//	remove_updater_fails_when_not_found()
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::remove_updater())]
+		pub fn remove_updater(origin: OriginFor<T>, updater: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				Updaters::<T>::get().contains(&updater),
+				Error::<T>::UpdaterNotFound
+			);
+
+			Self::announce_config_change(ConfigChange::RemoveUpdater(updater))
+		}
+
+		/// Sets how the authorized updaters take turns to submit updates, once the change is applied
+		/// after `ConfigChangeDelay`.
+		//
+		// Test names: set_updater_selection_works_with_root(), set_updater_selection_does_not_work_with_non_root(),
+		//	set_updater_selection_rejects_empty_turns()
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::set_updater_selection())]
+		pub fn set_updater_selection(
+			origin: OriginFor<T>,
+			selection: UpdaterSelection,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let UpdaterSelection::RoundRobin { turn_length } = selection {
+				ensure!(turn_length > 0, Error::<T>::InvalidUpdaterTurnLength);
+			}
+
+			Self::announce_config_change(ConfigChange::UpdaterSelection(selection))
+		}
+
+		/// Applies an announced configuration change whose delay has passed. Any signed origin can
+		/// apply it, before `on_initialize` does.
+		//
+		// Test names: apply_config_change_works_after_delay(), apply_config_change_fails_before_delay(),
+		//	apply_config_change_fails_when_not_found()
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::apply_config_change())]
+		pub fn apply_config_change(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: u32,
+		) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+			let pending =
+				PendingConfigChanges::<T>::get(id).ok_or(Error::<T>::ConfigChangeNotFound)?;
+			let block_number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			ensure!(
+				block_number >= pending.applicable_at,
+				Error::<T>::ConfigChangeNotApplicable
+			);
+
+			PendingConfigChanges::<T>::remove(id);
+			Self::apply_config(pending.change)?;
+			Self::deposit_event(Event::ConfigChangeApplied { id });
+
+			Ok(())
+		}
+
+		/// Cancels an announced configuration change before it is applied.
+		//
+		// Test names: cancel_config_change_works_with_root(), cancel_config_change_does_not_work_with_non_root()
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::cancel_config_change())]
+		pub fn cancel_config_change(
+			origin: OriginFor<T>,
+			#[pallet::compact] id: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			PendingConfigChanges::<T>::take(id).ok_or(Error::<T>::ConfigChangeNotFound)?;
+			Self::deposit_event(Event::ConfigChangeCancelled { id });
+
+			Ok(())
+		}
+
+		/// Removes the mapping of the Ethereum token `token_id`, which can no longer be bridged.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::deregister_asset())]
+		pub fn deregister_asset(origin: OriginFor<T>, token_id: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			let asset_id =
+				RegisteredAssets::<T>::take(token_id).ok_or(Error::<T>::AssetNotSupported)?;
+
+			Self::deposit_event(Event::AssetDeregistered { token_id, asset_id });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn do_send_message(
+			who: T::AccountId,
+			message: Message,
+			to: H256,
+			domain: u32,
+		) -> DispatchResultWithPostInfo {
+			// Ensure the domain is currently supported
+			ensure!(
+				Self::is_domain_valid(domain),
+				Error::<T>::DomainNotSupported
+			);
+			// Check MessageType and enforce the rules
+			let message_type = message.r#type();
+			match message {
+				Message::FungibleToken { asset_id, amount } => {
+					ensure!(
+						amount.saturated_into::<u128>() > 0,
+						Error::<T>::InvalidBridgeInputs
+					);
+					Self::consume_transfer_cap(
+						domain,
+						TransferDirection::Outbound,
+						asset_id,
+						amount,
+					)?;
+					Self::lock_asset(asset_id, &who, amount)?;
+				},
+				Message::ArbitraryMessage(data) => {
+					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
+				},
+			};
+
+			let message_id = Self::fetch_curr_message_id()?;
+			Self::log_outbound_message(
+				message_id,
+				OutboundMessage {
+					from: who.clone(),
+					to,
+					destination_domain: domain,
+					message_type,
+					block_number: <frame_system::Pallet<T>>::block_number().saturated_into(),
+					status: OutboundMessageStatus::Sent,
+				},
+			);
+
+			Self::deposit_event(Event::MessageSubmitted {
+				from: who,
+				to,
+				message_type,
+				destination_domain: domain,
+				message_id,
+			});
+
+			Ok(().into())
+		}
+
+		/// Verifies `account_proof` against the execution state root of `slot`, returning the storage root
+		/// of the `origin_domain` broadcaster.
+		pub(crate) fn broadcaster_storage_root(
+			slot: u64,
+			origin_domain: u32,
+			account_proof: &ValidProof,
+		) -> Result<H256, DispatchError> {
+			ensure!(
+				!SourceChainFrozen::<T>::get(origin_domain),
+				Error::<T>::SourceChainFrozen
+			);
+			ensure!(slot >= PrunedSlotsBelow::<T>::get(), Error::<T>::SlotPruned);
+			let root = ExecutionStateRoots::<T>::get(slot);
+			let broadcaster = Broadcasters::<T>::get(origin_domain);
+
+			// extract contract address
+			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
+			let account_proof_vec = account_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			let storage_root =
+				get_storage_root(account_proof_vec, contract_broadcaster_address, root)
+					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
+
+			Ok(storage_root)
+		}
+
+		/// Verifies that `addr_message` is stored in the broadcaster storage and executes it.
+		fn execute_message(
+			relayer: &T::AccountId,
+			addr_message: AddressedMessage,
+			message_root: H256,
+			storage_root: H256,
+			storage_proof: &ValidProof,
+		) -> Result<Weight, DispatchError> {
+			let message_id = Uint(U256::from(addr_message.id));
+			let mm_idx = Uint(U256::from(T::MessageMappingStorageIndex::get()));
+			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
+
+			let storage_proof_vec = storage_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
+				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+
+			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
+
+			Self::apply_message(relayer, addr_message, message_root)
+		}
+
+		/// Verifies that `receipts_root` is the receipts root of the execution payload of the header of `slot`.
+		fn verify_receipts_root(
+			slot: u64,
+			origin_domain: u32,
+			receipts_root: H256,
+			branch: &ReceiptsRootBranch,
+		) -> DispatchResult {
+			ensure!(
+				!SourceChainFrozen::<T>::get(origin_domain),
+				Error::<T>::SourceChainFrozen
+			);
+			ensure!(slot >= PrunedSlotsBelow::<T>::get(), Error::<T>::SlotPruned);
+			let header = Headers::<T>::get(slot);
+			ensure!(header != H256::zero(), Error::<T>::HeaderRootNotSet);
+
+			ensure!(
+				is_valid_merkle_branch(receipts_root, branch, RECEIPTS_ROOT_GINDEX, header),
+				Error::<T>::InvalidReceiptsRoot
+			);
+
+			Ok(())
+		}
+
+		/// Verifies that the broadcaster of `origin_domain` emitted the log of `message_root` at `log_index`
+		/// in the receipt of the transaction at `tx_index`.
+		fn verify_message_log(
+			origin_domain: u32,
+			message_root: H256,
+			receipts_root: H256,
+			tx_index: u32,
+			log_index: u32,
+			receipt_proof: &ValidProof,
+		) -> DispatchResult {
+			let topic = BroadcasterLogTopics::<T>::get(origin_domain)
+				.ok_or(Error::<T>::BroadcasterLogTopicNotSet)?;
+			let broadcaster = Broadcasters::<T>::get(origin_domain);
+			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
+
+			let receipt_proof_vec = receipt_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			let (address, topics) =
+				get_receipt_log(tx_index, log_index, receipts_root, receipt_proof_vec)
+					.map_err(|_| Error::<T>::CannotGetReceiptLog)?;
+
+			ensure!(
+				address == contract_broadcaster_address
+					&& topics.first() == Some(&topic)
+					&& topics.get(1) == Some(&message_root),
+				Error::<T>::InvalidMessageLog
+			);
+
+			Ok(())
+		}
+
+		/// Executes the verified `addr_message`, paying the execution fee of its domain to `relayer`.
+		/// Returns the message handler weight that was not consumed.
+		///
+		/// The fee of a `FungibleToken` message is a share of its amount. `ArbitraryMessage`s carry no
+		/// proven value to pay a fee from, so their relayers are not paid.
+		fn apply_message(
+			relayer: &T::AccountId,
+			addr_message: AddressedMessage,
+			message_root: H256,
+		) -> Result<Weight, DispatchError> {
+			let execution_fee = ExecutionFees::<T>::get(addr_message.origin_domain);
+			let fee = match &addr_message.message {
+				Message::FungibleToken { asset_id, amount } => {
+					let destination_account_id =
+						T::AccountId::decode(&mut &addr_message.to.encode()[..])
+							.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
+
+					Self::consume_transfer_cap(
+						addr_message.origin_domain,
+						TransferDirection::Inbound,
+						*asset_id,
+						*amount,
+					)?;
+					let fee = execution_fee.fungible_token.mul_floor(*amount);
+					Self::release_asset(
+						*asset_id,
+						&destination_account_id,
+						amount.saturating_sub(fee),
+					)?;
+					if fee > 0 {
+						Self::release_asset(*asset_id, relayer, fee)?;
+					}
+					fee
+				},
+				Message::ArbitraryMessage(_) => 0,
+			};
+			if fee > 0 {
+				Self::deposit_event(Event::<T>::ExecutionFeePaid {
+					message_id: addr_message.id,
+					relayer: relayer.clone(),
+					fee,
+				});
+			}
+
+			let unused_weight = match &addr_message.message {
+				Message::ArbitraryMessage(data) => {
+					let weight_limit = T::MaxMessageHandlerWeight::get();
+					let consumed = T::MessageHandler::handle(
+						addr_message.origin_domain,
+						addr_message.from,
+						addr_message.to,
+						data,
+						weight_limit,
+					)?;
+					ensure!(
+						consumed.all_lte(weight_limit),
+						Error::<T>::MessageHandlerWeightExceeded
+					);
+					weight_limit.saturating_sub(consumed)
+				},
+				Message::FungibleToken { .. } => Weight::zero(),
+			};
+
+			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
+			Self::deposit_event(Event::<T>::MessageExecuted {
+				from: addr_message.from,
+				to: addr_message.to,
+				message_id: addr_message.id,
+				message_root,
+			});
+
+			Ok(unused_weight)
+		}
+
+		/// Records the failed `send_message` transactions of the block, logs their messages and emits
+		/// their errors.
+		pub(crate) fn report_failed_send_messages(
+			failed_txs: Vec<Compact<u32>>,
+			errors: Vec<(u32, OutboundMessageOf<T>, DispatchError)>,
+		) {
+			for (tx_index, message, error) in errors {
+				Self::deposit_event(Event::MessageSendFailed {
+					who: message.from.clone(),
+					tx_index,
+					error,
+				});
+				Self::log_outbound_message(tx_uid(message.block_number, tx_index), message);
+			}
+			FailedSendMessageTxs::<T>::put(
+				failed_txs
+					.into_iter()
+					.map(|tx_index| tx_index.0)
+					.collect::<Vec<_>>(),
+			);
+		}
+
+		/// Accounts `amount` of `token_id` against the transfer cap of `domain` in `direction` for the
+		/// current period.
+		fn consume_transfer_cap(
+			domain: u32,
+			direction: TransferDirection,
+			token_id: H256,
+			amount: u128,
+		) -> DispatchResult {
+			let key = (domain, direction, token_id);
+			ensure!(!PausedTransfers::<T>::get(key), Error::<T>::TransfersPaused);
+			let Some(cap) = TransferCaps::<T>::get(key) else {
+				return Ok(());
+			};
+
+			let block_number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let period = block_number / T::TransferCapPeriod::get().max(1);
+			let (usage_period, usage) = TransferUsage::<T>::get(key);
+			let usage = if usage_period == period { usage } else { 0 };
+
+			let usage = usage.saturating_add(amount);
+			ensure!(usage <= cap, Error::<T>::TransferCapExceeded);
+			TransferUsage::<T>::insert(key, (period, usage));
+
+			Ok(())
+		}
+
+		fn is_cap_exceeded(error: &DispatchError) -> bool {
+			let cap_exceeded: DispatchError = Error::<T>::TransferCapExceeded.into();
+			*error == cap_exceeded
+		}
+
+		/// The token moved by `message`, whose transfers are capped.
+		fn capped_token_id(message: &Message) -> Option<H256> {
+			match message {
+				Message::FungibleToken { asset_id, .. } => Some(*asset_id),
+				Message::ArbitraryMessage(_) => None,
+			}
+		}
+
+		fn pause_transfers(domain: u32, direction: TransferDirection, token_id: H256) {
+			PausedTransfers::<T>::insert((domain, direction, token_id), true);
+			Self::deposit_event(Event::TransfersPaused {
+				domain,
+				direction,
+				token_id,
+			});
+		}
+
+		/// Queues `slot` for pruning once it falls out of the retention window, and records the head
+		/// update for the liveness check.
+		fn track_fulfilled_slot(slot: u64) {
+			FulfilledSlotsBounds::<T>::mutate(|(_, next)| {
+				FulfilledSlots::<T>::insert(*next, slot);
+				*next = next.saturating_add(1);
+			});
+			LastUpdateBlock::<T>::put(
+				<frame_system::Pallet<T>>::block_number().saturated_into::<u32>(),
+			);
+		}
+
+		/// Returns `Updater`, if set, followed by the added updaters.
+		pub fn authorized_updaters() -> Vec<H256> {
+			let updater = Updater::<T>::get();
+			let updater = (!updater.is_zero()).then_some(updater);
+			updater.into_iter().chain(Updaters::<T>::get()).collect()
+		}
+
+		/// Ensures `sender` is an authorized updater whose turn it is to submit updates.
+		fn ensure_updater(sender: H256) -> DispatchResult {
+			let updaters = Self::authorized_updaters();
+			ensure!(updaters.contains(&sender), Error::<T>::UpdaterMisMatch);
+
+			if let UpdaterSelection::RoundRobin { turn_length } =
+				UpdaterSelectionStorage::<T>::get()
+			{
+				let block_number =
+					<frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+				let turn = block_number / turn_length.max(1);
+				let index = turn as usize % updaters.len();
+				ensure!(updaters[index] == sender, Error::<T>::NotUpdaterTurn);
+			}
+
+			Ok(())
+		}
+
+		/// Emits `UpdatesStalled` every `UpdaterLivenessPeriod` blocks without a head update.
+		/// Returns the weight consumed: the read of `LastUpdateBlock` and the deposit of the event.
+		fn check_updates_liveness(block_number: u32) -> Weight {
+			let Some(last_update) = LastUpdateBlock::<T>::get() else {
+				return T::DbWeight::get().reads(1);
+			};
+			let blocks_since = block_number.saturating_sub(last_update);
+			if blocks_since > 0 && blocks_since % T::UpdaterLivenessPeriod::get().max(1) == 0 {
+				Self::deposit_event(Event::UpdatesStalled { last_update });
+				return T::DbWeight::get().reads_writes(1, 1);
+			}
+
+			T::DbWeight::get().reads(1)
+		}
+
+		/// Queues `change` until `ConfigChangeDelay` passes, or applies it right away without delay.
+		fn announce_config_change(change: ConfigChange) -> DispatchResult {
+			let delay = T::ConfigChangeDelay::get();
+			if delay == 0 {
+				return Self::apply_config(change);
+			}
+
+			let block_number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let applicable_at = block_number.saturating_add(delay);
+			let id = NextConfigChangeId::<T>::mutate(|next| {
+				let id = *next;
+				*next = next.wrapping_add(1);
+				id
+			});
+			PendingConfigChanges::<T>::insert(
+				id,
+				PendingConfigChange {
+					change: change.clone(),
+					applicable_at,
+				},
+			);
+			Self::deposit_event(Event::ConfigChangeAnnounced {
+				id,
+				change,
+				applicable_at,
+			});
+
+			Ok(())
+		}
+
+		/// Applies the announced configuration changes whose delay has passed, in the order they
+		/// were announced, and returns the weight consumed. A change that can no longer be applied is
+		/// dropped.
+		///
+		/// Visits at most `MAX_CONFIG_CHANGES_PER_BLOCK` changes, the next ones are applied in the
+		/// following blocks.
+		fn apply_due_config_changes(block_number: u32) -> Weight {
+			let next_id = NextConfigChangeId::<T>::get();
+			let mut id = NextConfigChangeToApply::<T>::get();
+			let mut visited = 0u32;
+
+			while id != next_id && visited < MAX_CONFIG_CHANGES_PER_BLOCK {
+				if let Some(pending) = PendingConfigChanges::<T>::get(id) {
+					if pending.applicable_at > block_number {
+						break;
+					}
+
+					PendingConfigChanges::<T>::remove(id);
+					match Self::apply_config(pending.change) {
+						Ok(()) => Self::deposit_event(Event::ConfigChangeApplied { id }),
+						Err(error) => Self::deposit_event(Event::ConfigChangeFailed { id, error }),
+					}
+				}
+				visited = visited.saturating_add(1);
+				id = id.wrapping_add(1);
+			}
+
+			if visited == 0 {
+				return T::DbWeight::get().reads(2);
+			}
+			NextConfigChangeToApply::<T>::put(id);
+
+			T::DbWeight::get()
+				.reads_writes(2, 1)
+				.saturating_add(T::WeightInfo::apply_config_change().saturating_mul(visited.into()))
+		}
+
+		fn apply_config(change: ConfigChange) -> DispatchResult {
+			match change {
+				ConfigChange::SP1VerificationKey(new) => {
+					let old = SP1VerificationKey::<T>::get();
+					SP1VerificationKey::<T>::put(new);
+					Self::deposit_event(Event::NewSP1VerificationKey { old, new });
+				},
+				ConfigChange::Broadcaster {
+					domain,
+					broadcaster,
+				} => {
+					let old = Broadcasters::<T>::get(domain);
+					Broadcasters::<T>::set(domain, broadcaster);
+					Self::deposit_event(Event::BroadcasterUpdated {
+						old,
+						new: broadcaster,
+						domain,
+					});
+				},
+				ConfigChange::WhitelistedDomains(value) => {
+					WhitelistedDomains::<T>::put(value);
+					Self::deposit_event(Event::WhitelistedDomainsUpdated);
+				},
+				ConfigChange::Updater(new) => {
+					let old = Updater::<T>::get();
+					Updater::<T>::set(new);
+					Self::deposit_event(Event::<T>::NewUpdater { old, new });
+				},
+				ConfigChange::FunctionIds(value) => {
+					FunctionIds::<T>::put(value);
+					Self::deposit_event(Event::FunctionIdsUpdated { value });
+				},
+				ConfigChange::StepVerificationKey(value) => {
+					StepVerificationKey::<T>::put(value.clone());
+					Self::deposit_event(Event::StepVerificationKeyUpdated { value });
+				},
+				ConfigChange::RotateVerificationKey(value) => {
+					RotateVerificationKey::<T>::put(value.clone());
+					Self::deposit_event(Event::RotateVerificationKeyUpdated { value });
+				},
+				ConfigChange::ProofSystem { vk, system } => {
+					ProofSystems::<T>::insert(vk, system);
+					Self::deposit_event(Event::ProofSystemSet { vk, system });
+				},
+				ConfigChange::AddUpdater(updater) => {
+					ensure!(
+						!Self::authorized_updaters().contains(&updater),
+						Error::<T>::UpdaterAlreadyAuthorized
+					);
+					Updaters::<T>::try_append(updater).map_err(|_| Error::<T>::TooManyUpdaters)?;
+					Self::deposit_event(Event::UpdaterAdded { updater });
+				},
+				ConfigChange::RemoveUpdater(updater) => {
+					Updaters::<T>::try_mutate(|updaters| {
+						let index = updaters
+							.iter()
+							.position(|added| *added == updater)
+							.ok_or(Error::<T>::UpdaterNotFound)?;
+						updaters.remove(index);
+						Ok::<_, Error<T>>(())
+					})?;
+					Self::deposit_event(Event::UpdaterRemoved { updater });
+				},
+				ConfigChange::UpdaterSelection(selection) => {
+					UpdaterSelectionStorage::<T>::put(selection);
+					Self::deposit_event(Event::UpdaterSelectionSet { selection });
+				},
+				ConfigChange::BroadcasterLogTopic { domain, topic } => {
+					BroadcasterLogTopics::<T>::set(domain, topic);
+					Self::deposit_event(Event::BroadcasterLogTopicSet { domain, topic });
+				},
+				#[cfg(any(test, feature = "vector-mock"))]
+				ConfigChange::MockEnabled(value) => {
+					MockEnabled::<T>::set(value);
+					Self::deposit_event(Event::MockEnabled { value });
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Prunes the oldest fulfilled slots out of the retention window, as long as `meter` allows it.
+		pub(crate) fn prune_slots(meter: &mut WeightMeter) {
+			let bounds_weight = T::DbWeight::get().reads_writes(1, 1);
+			if meter.try_consume(bounds_weight).is_err() {
+				return;
+			}
+			let (mut first, next) = FulfilledSlotsBounds::<T>::get();
+			let prune_weight = T::WeightInfo::prune_slot();
+
+			while first < next && meter.try_consume(prune_weight).is_ok() {
+				let Some(slot) = FulfilledSlots::<T>::get(first) else {
+					first = first.saturating_add(1);
+					continue;
+				};
+				if !Self::is_slot_prunable(slot) {
+					break;
+				}
+
+				Self::remove_slot_state(slot);
+				// The queue is ordered, so every fulfilled slot below this one is pruned too.
+				PrunedSlotsBelow::<T>::mutate(|below| {
+					*below = (*below).max(slot.saturating_add(1))
+				});
+				FulfilledSlots::<T>::remove(first);
+				first = first.saturating_add(1);
+			}
+
+			FulfilledSlotsBounds::<T>::put((first, next));
+		}
+
+		/// Prunes the periods out of the retention window, as long as `meter` allows it.
+		pub(crate) fn prune_periods(meter: &mut WeightMeter) {
+			let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+			if meter.try_consume(cursor_weight).is_err() {
+				return;
+			}
+			let mut cursor = PeriodPruningCursor::<T>::get();
+			let prune_weight = T::WeightInfo::prune_period();
+
+			while Self::is_period_prunable(cursor) && meter.try_consume(prune_weight).is_ok() {
+				Self::remove_period_state(cursor);
+				cursor = cursor.saturating_add(1);
+			}
+
+			PeriodPruningCursor::<T>::put(cursor);
+		}
+
+		/// Appends the message `message_id` to the outbound message log.
+		fn log_outbound_message(message_id: u64, message: OutboundMessageOf<T>) {
+			OutboundMessageIdsBounds::<T>::mutate(|(_, next)| {
+				OutboundMessages::<T>::insert(message_id, (*next, message));
+				OutboundMessageIds::<T>::insert(*next, message_id);
+				*next = next.saturating_add(1);
+			});
+		}
+
+		/// Prunes the oldest outbound messages out of the retention window, as long as `meter` allows it.
+		pub(crate) fn prune_outbound_messages(meter: &mut WeightMeter) {
+			let bounds_weight = T::DbWeight::get().reads_writes(1, 1);
+			if meter.try_consume(bounds_weight).is_err() {
+				return;
+			}
+			let (mut first, next) = OutboundMessageIdsBounds::<T>::get();
+			let prune_weight = T::WeightInfo::prune_outbound_message();
+			let block_number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+
+			while first < next && meter.try_consume(prune_weight).is_ok() {
+				let Some(message_id) = OutboundMessageIds::<T>::get(first) else {
+					first = first.saturating_add(1);
+					continue;
+				};
+				if let Some((_, message)) = OutboundMessages::<T>::get(message_id) {
+					let retained_until = message
+						.block_number
+						.saturating_add(T::OutboundMessageRetention::get());
+					if retained_until >= block_number {
+						break;
+					}
+					OutboundMessages::<T>::remove(message_id);
+				}
+
+				OutboundMessageIds::<T>::remove(first);
+				first = first.saturating_add(1);
+			}
+
+			OutboundMessageIdsBounds::<T>::put((first, next));
+		}
+
+		/// Returns up to `limit` outbound messages with their ids, starting after the message
+		/// `start_after`.
+		///
+		/// Messages are returned in the order they were logged, the failed messages of a block
+		/// are logged after its sent ones. Nothing is returned if `start_after` is not logged.
+		pub fn outbound_messages(
+			start_after: Option<u64>,
+			limit: u32,
+		) -> Vec<(u64, OutboundMessageOf<T>)> {
+			let (first, next) = OutboundMessageIdsBounds::<T>::get();
+			let start = match start_after {
+				Some(message_id) => match OutboundMessages::<T>::get(message_id) {
+					Some((index, _)) => index.saturating_add(1),
+					None => return Vec::new(),
+				},
+				None => first,
+			};
+
+			(start..next)
+				.filter_map(|index| {
+					let message_id = OutboundMessageIds::<T>::get(index)?;
+					let (_, message) = OutboundMessages::<T>::get(message_id)?;
+					Some((message_id, message))
+				})
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// Returns the outbound message `message_id`, if it is logged.
+		pub fn outbound_message(message_id: u64) -> Option<OutboundMessageOf<T>> {
+			OutboundMessages::<T>::get(message_id).map(|(_, message)| message)
+		}
+
+		fn is_slot_prunable(slot: u64) -> bool {
+			slot.saturating_add(T::SlotRetention::get()) < Head::<T>::get()
+		}
+
+		fn is_period_prunable(period: u64) -> bool {
+			let slots_per_period = ConfigurationStorage::<T>::get().slots_per_period;
+			let Some(current_period) = Head::<T>::get().checked_div(slots_per_period) else {
+				return false;
+			};
+			period.saturating_add(T::PeriodRetention::get()) < current_period
+		}
+
+		fn remove_slot_state(slot: u64) {
+			Headers::<T>::remove(slot);
+			Timestamps::<T>::remove(slot);
+			ExecutionStateRoots::<T>::remove(slot);
+		}
+
+		fn remove_period_state(period: u64) {
+			SyncCommitteePoseidons::<T>::remove(period);
+			SyncCommitteeHashes::<T>::remove(period);
+		}
+
+		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
+			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let tx_index_option = <frame_system::Pallet<T>>::extrinsic_index();
+
+			match tx_index_option {
+				Some(tx_index) => Ok(tx_uid(number, tx_index)),
+				None => Err(Error::<T>::CurrentMessageIdNotFound.into()),
+			}
+		}
+
+		fn check_preconditions(
+			message: &AddressedMessage,
+			message_root: H256,
+		) -> Result<(), DispatchError> {
+			let message_status = MessageStatus::<T>::get(message_root);
+			// Message must not be executed
+			ensure!(
+				message_status == MessageStatusEnum::NotExecuted,
+				Error::<T>::MessageAlreadyExecuted
+			);
+
+			ensure!(
+				message.destination_domain == T::AvailDomain::get(),
+				Error::<T>::WrongDestinationChain
+			);
+
+			ensure!(
+				WhitelistedDomains::<T>::get().contains(&message.origin_domain),
+				Error::<T>::UnsupportedOriginChain
+			);
+
+			let source_chain = Broadcasters::<T>::get(message.origin_domain);
+			ensure!(
+				source_chain != H256::zero(),
+				Error::<T>::BroadcasterSourceChainNotSet
+			);
+
+			Ok(())
+		}
+
+		/// The account ID of the bridge's pot.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Moves `amount` of `token_id` out of `who` before it is bridged.
+		/// AVAIL is locked in the bridge's pot, registered assets are burned.
+		fn lock_asset(token_id: H256, who: &T::AccountId, amount: u128) -> DispatchResult {
+			if token_id == SUPPORTED_ASSET_ID {
+				return T::Currency::transfer(
+					who,
+					&Self::account_id(),
+					amount.saturated_into(),
+					ExistenceRequirement::KeepAlive,
+				);
+			}
+
+			let asset_id =
+				RegisteredAssets::<T>::get(token_id).ok_or(Error::<T>::AssetNotSupported)?;
+			T::Assets::burn_from(
+				asset_id,
+				who,
+				amount.saturated_into(),
+				Precision::Exact,
+				Fortitude::Polite,
+			)?;
+			Ok(())
+		}
+
+		/// Credits `amount` of `token_id` bridged to `dest`.
+		/// AVAIL is released from the bridge's pot, registered assets are minted.
+		fn release_asset(token_id: H256, dest: &T::AccountId, amount: u128) -> DispatchResult {
+			if token_id == SUPPORTED_ASSET_ID {
+				return T::Currency::transfer(
+					&Self::account_id(),
+					dest,
+					amount.saturated_into(),
+					ExistenceRequirement::AllowDeath,
+				);
+			}
+
+			let asset_id =
+				RegisteredAssets::<T>::get(token_id).ok_or(Error::<T>::AssetNotSupported)?;
+			T::Assets::mint_into(asset_id, dest, amount.saturated_into())?;
+			Ok(())
+		}
+
+		fn rotate_into(
+			finalized_slot: u64,
 			cfg: &Configuration,
 			verified_rotate_call: &VerifiedRotate,
 			rotate_function_id: H256,
@@ -1245,6 +2573,7 @@ pub mod pallet {
 				step_output.finalized_slot,
 				step_output.execution_state_root,
 			);
+			Self::track_fulfilled_slot(step_output.finalized_slot);
 
 			Timestamps::<T>::insert(step_output.finalized_slot, T::TimeProvider::now().as_secs());
 
@@ -1380,6 +2709,39 @@ where
 pub mod weight_helper {
 	use super::*;
 
+	/// Weight for `vector::execute`.
+	pub fn execute<T: Config>(message: &Message) -> Weight {
+		match message {
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+					.saturating_add(T::MaxMessageHandlerWeight::get())
+			},
+			Message::FungibleToken { .. } => T::WeightInfo::execute_fungible_token(),
+		}
+	}
+
+	/// Weight for `vector::execute_via_receipt`.
+	pub fn execute_via_receipt<T: Config>(message: &Message) -> Weight {
+		match message {
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_via_receipt_arbitrary_message(data.len() as u32)
+					.saturating_add(T::MaxMessageHandlerWeight::get())
+			},
+			Message::FungibleToken { .. } => T::WeightInfo::execute_via_receipt_fungible_token(),
+		}
+	}
+
+	/// Weight for `vector::execute_batch`, the account proof is only verified once.
+	pub fn execute_batch<T: Config>(messages: &MessageBatch) -> Weight {
+		let executions = messages.iter().fold(Weight::zero(), |acc, (message, _)| {
+			acc.saturating_add(execute::<T>(&message.message))
+		});
+		let skipped_proofs = (messages.len() as u64).saturating_sub(1);
+
+		executions
+			.saturating_sub(T::WeightInfo::verify_account_proof().saturating_mul(skipped_proofs))
+	}
+
 	/// Weight for `dataAvailability::submit_data`.
 	pub fn fulfill_call<T: Config>(function_id: H256) -> (Weight, DispatchClass) {
 		if let Some((step_function_id, _)) = FunctionIds::<T>::get() {
@@ -0,0 +1,50 @@
+use frame_support::{dispatch::DispatchError, weights::Weight};
+use sp_core::H256;
+
+/// Receives the `ArbitraryMessage`s executed by the bridge.
+///
+/// Implemented for tuples, the first handler that accepts the `(origin_domain, from)` pair of a
+/// message handles it. Messages that no handler accepts are executed without being dispatched.
+pub trait MessageHandler {
+	/// Whether the messages sent by `from` on `origin_domain` are handled by this handler.
+	fn accepts(origin_domain: u32, from: H256) -> bool;
+
+	/// Handles the payload of a message, returning the consumed weight.
+	///
+	/// The consumed weight must not exceed `weight_limit`. On error the message is not executed
+	/// and its changes are reverted, so it can be executed again.
+	fn handle(
+		origin_domain: u32,
+		from: H256,
+		to: H256,
+		data: &[u8],
+		weight_limit: Weight,
+	) -> Result<Weight, DispatchError>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl MessageHandler for Tuple {
+	fn accepts(origin_domain: u32, from: H256) -> bool {
+		for_tuples!( #(
+			if Tuple::accepts(origin_domain, from) {
+				return true;
+			}
+		)* );
+		false
+	}
+
+	fn handle(
+		origin_domain: u32,
+		from: H256,
+		to: H256,
+		data: &[u8],
+		weight_limit: Weight,
+	) -> Result<Weight, DispatchError> {
+		for_tuples!( #(
+			if Tuple::accepts(origin_domain, from) {
+				return Tuple::handle(origin_domain, from, to, data, weight_limit);
+			}
+		)* );
+		Ok(Weight::zero())
+	}
+}
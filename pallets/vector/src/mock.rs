@@ -1,13 +1,21 @@
-use frame_support::{derive_impl, parameter_types, traits::ConstU64, PalletId};
-use frame_system::{native::hosted_header_builder::da, test_utils::TestRandomness};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64},
+	weights::Weight,
+	PalletId,
+};
+use frame_system::{
+	native::hosted_header_builder::da, test_utils::TestRandomness, EnsureRoot, EnsureSigned,
+};
 use hex_literal::hex;
 use primitive_types::H256;
 use sp_runtime::{
 	traits::{Block as BlockT, IdentityLookup},
-	AccountId32, BuildStorage,
+	AccountId32, BuildStorage, DispatchError,
 };
 
 use crate as vector_bridge;
+use crate::MessageHandler;
 
 type Balance = u128;
 type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -167,6 +175,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Timestamp: pallet_timestamp,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		Bridge: vector_bridge,
 	}
 );
@@ -208,14 +217,67 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_assets::Config for Test {
+	type ApprovalDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Balance = u128;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type CallbackHandle = ();
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type Currency = Balances;
+	type Extra = ();
+	type ForceOrigin = EnsureRoot<AccountId32>;
+	type Freezer = ();
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = ConstU32<50>;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+	pub static HandledMessages: Vec<(u32, H256, H256, Vec<u8>)> = vec![];
+	pub static MessageHandlerWeight: Weight = Weight::from_parts(1_000, 0);
+	pub static ConfigChangeDelay: u32 = 0;
+}
+
+pub const HANDLED_SENDER: H256 = H256(hex!(
+	"681257BED628425a28B469114Dc21A7c30205cFD000000000000000000000000"
+));
+
+/// Records the messages sent by `HANDLED_SENDER` on domain 2.
+pub struct TestMessageHandler;
+
+impl MessageHandler for TestMessageHandler {
+	fn accepts(origin_domain: u32, from: H256) -> bool {
+		origin_domain == 2 && from == HANDLED_SENDER
+	}
+
+	fn handle(
+		origin_domain: u32,
+		from: H256,
+		to: H256,
+		data: &[u8],
+		_weight_limit: Weight,
+	) -> Result<Weight, DispatchError> {
+		HandledMessages::mutate(|messages| messages.push((origin_domain, from, to, data.to_vec())));
+		Ok(MessageHandlerWeight::get())
+	}
 }
 
 #[derive_impl(crate::config_preludes::TestDefaultConfig as crate::DefaultConfig)]
 impl vector_bridge::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type Assets = Assets;
+	type MessageHandler = TestMessageHandler;
+	type ConfigChangeDelay = ConfigChangeDelay;
 }
 
 /// Create new externalities for `Vector` module tests.
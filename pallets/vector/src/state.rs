@@ -6,10 +6,12 @@ use ark_groth16::Proof;
 use ark_std::str::FromStr;
 use ark_std::string::String;
 use ark_std::string::ToString;
+use avail_core::data_proof::MessageType;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{Deserialize, Serialize};
+use frame_support::{traits::ConstU32, BoundedVec, Deserialize, Serialize};
 use scale_info::TypeInfo;
 use sp_core::{H256, U256};
+use sp_runtime::Permill;
 use sp_std::prelude::*;
 
 use crate::verifier::{str_to_fq, VerificationError};
@@ -91,6 +93,114 @@ pub struct Configuration {
 	pub finality_threshold: u16,
 }
 
+/// Direction of the value moved by the bridge, used to cap transfers.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TransferDirection {
+	/// Tokens released on Avail by executed messages.
+	Inbound,
+	/// Tokens locked or burned on Avail by sent messages.
+	Outbound,
+}
+
+/// Proof system of an SP1 program verification key.
+#[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ProofSystem {
+	/// SP1 Groth16 proofs, used by the verification keys without a proof system set.
+	#[default]
+	Groth16Sp1,
+	/// SP1 PLONK proofs.
+	PlonkSp1,
+}
+
+/// How the authorized updaters take turns to update the light client.
+#[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum UpdaterSelection {
+	/// Any authorized updater can submit the next update.
+	#[default]
+	FirstCome,
+	/// The authorized updaters take turns of `turn_length` blocks, in the order they were authorized.
+	RoundRobin {
+		#[codec(compact)]
+		turn_length: u32,
+	},
+}
+
+/// Fees paid to the relayer that executes a message from a domain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default)]
+pub struct ExecutionFee {
+	/// Share of the bridged amount of a `FungibleToken` message.
+	pub fungible_token: Permill,
+}
+
+/// Splits the id of a message sent from Avail, the `tx_uid` of its `send_message` transaction,
+/// into its block number and transaction index.
+pub fn split_message_id(message_id: u64) -> (u32, u32) {
+	((message_id >> 32) as u32, message_id as u32)
+}
+
+/// Status of a message sent from Avail.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum OutboundMessageStatus {
+	/// Included in the bridge root of its block.
+	Sent,
+	/// Failed and left out of the bridge root of its block.
+	Failed,
+}
+
+/// A message sent from Avail, as recorded in the outbound message log.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OutboundMessage<AccountId> {
+	pub from: AccountId,
+	pub to: H256,
+	pub destination_domain: u32,
+	pub message_type: MessageType,
+	/// The block that includes the `send_message` transaction.
+	pub block_number: u32,
+	pub status: OutboundMessageStatus,
+}
+
+/// A sensitive configuration change, applied `ConfigChangeDelay` blocks after it is announced.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum ConfigChange {
+	SP1VerificationKey(H256),
+	Broadcaster {
+		#[codec(compact)]
+		domain: u32,
+		broadcaster: H256,
+	},
+	WhitelistedDomains(BoundedVec<u32, ConstU32<10_000>>),
+	Updater(H256),
+	FunctionIds(Option<(H256, H256)>),
+	StepVerificationKey(Option<BoundedVec<u8, ConstU32<10_000>>>),
+	RotateVerificationKey(Option<BoundedVec<u8, ConstU32<10_000>>>),
+	ProofSystem {
+		vk: H256,
+		system: ProofSystem,
+	},
+	AddUpdater(H256),
+	RemoveUpdater(H256),
+	UpdaterSelection(UpdaterSelection),
+	BroadcasterLogTopic {
+		#[codec(compact)]
+		domain: u32,
+		topic: Option<H256>,
+	},
+	#[cfg(any(test, feature = "vector-mock"))]
+	MockEnabled(bool),
+}
+
+/// An announced configuration change waiting for its delay to pass.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PendingConfigChange {
+	pub change: ConfigChange,
+	/// The first block in which the change can be applied.
+	pub applicable_at: u32,
+}
+
 /// VerifiedStep struct that holds verified params from a step call.
 #[derive(Default, Debug)]
 pub struct VerifiedStep {
@@ -1,13 +1,15 @@
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{Deserialize, Serialize};
 use patricia_merkle_trie::{keccak256, EIP1186Layout, StorageProof};
 use primitive_types::{H160, H256};
 use rlp::Rlp;
 use scale_info::TypeInfo;
-use sp_io::hashing::keccak_256 as keccak256;
+use sp_io::hashing::{keccak_256 as keccak256, sha2_256};
 use sp_std::vec::Vec;
 use trie_db::{Trie, TrieDBBuilder};
 
 #[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum MessageStatusEnum {
 	#[default]
 	NotExecuted,
@@ -83,6 +85,79 @@ pub fn get_storage_root(
 	Ok(storage_root)
 }
 
+/// get_receipt_log returns the address and topics of the log at `log_index` in the receipt of the
+/// transaction at `tx_index`, based on the proof that is provided.
+pub fn get_receipt_log(
+	tx_index: u32,
+	log_index: u32,
+	receipts_root: H256,
+	proof: Vec<Vec<u8>>,
+) -> Result<(H160, Vec<H256>), StorageError> {
+	let key = rlp::encode(&tx_index);
+	let db = StorageProof::new(proof).into_memory_db::<keccak256::KeccakHasher>();
+	let trie =
+		TrieDBBuilder::<EIP1186Layout<keccak256::KeccakHasher>>::new(&db, &receipts_root).build();
+
+	let Ok(Some(receipt)) = trie.get(&key[..]) else {
+		return Err(StorageError::StorageValueError);
+	};
+
+	// EIP-2718 typed receipts are prefixed with their transaction type, legacy ones are RLP lists.
+	let payload = match receipt.first() {
+		Some(tx_type) if *tx_type <= 0x7f => &receipt[1..],
+		Some(_) => &receipt[..],
+		None => return Err(StorageError::CannotDecodeItems),
+	};
+
+	let log = Rlp::new(payload)
+		.at(3)
+		.and_then(|logs| logs.at(log_index as usize))
+		.map_err(|_| StorageError::CannotDecodeItems)?;
+
+	let Ok(address) = log.at(0).and_then(|a| a.data()) else {
+		return Err(StorageError::CannotDecodeItems);
+	};
+	if address.len() != H160::len_bytes() {
+		return Err(StorageError::CannotDecodeItems);
+	}
+
+	let topics = log
+		.at(1)
+		.and_then(|topics| {
+			topics
+				.iter()
+				.map(|topic| topic.data())
+				.collect::<Result<Vec<_>, _>>()
+		})
+		.map_err(|_| StorageError::CannotDecodeItems)?;
+	if topics.iter().any(|topic| topic.len() != H256::len_bytes()) {
+		return Err(StorageError::CannotDecodeItems);
+	}
+
+	Ok((
+		H160::from_slice(address),
+		topics.into_iter().map(H256::from_slice).collect(),
+	))
+}
+
+/// is_valid_merkle_branch verifies the SSZ merkle `branch` of `leaf` at the generalized index `gindex` against `root`.
+pub fn is_valid_merkle_branch(leaf: H256, branch: &[H256], gindex: u64, root: H256) -> bool {
+	let mut node = leaf;
+	let mut index = gindex;
+	for sibling in branch {
+		let (left, right) = if index % 2 == 1 {
+			(sibling, &node)
+		} else {
+			(&node, sibling)
+		};
+		node = H256(sha2_256(&[left.as_bytes(), right.as_bytes()].concat()));
+		index /= 2;
+	}
+
+	// The branch must reach the root from the depth of `gindex`.
+	index == 1 && node == root
+}
+
 fn rlp_to_h256(value: &[u8]) -> Result<H256, StorageError> {
 	const H256_LENGTH: usize = 32;
 
@@ -258,4 +333,29 @@ mod test {
 
 		assert_err!(error, StorageError::CannotDecodeItems);
 	}
+
+	#[test]
+	fn is_valid_merkle_branch_works() {
+		let leaf = H256::repeat_byte(1);
+		let sibling = H256::repeat_byte(2);
+		let uncle = H256::repeat_byte(3);
+		// `leaf` is the right child of its parent, which is the left child of the root.
+		let parent = H256(sha2_256(&[sibling.as_bytes(), leaf.as_bytes()].concat()));
+		let root = H256(sha2_256(&[parent.as_bytes(), uncle.as_bytes()].concat()));
+
+		assert!(is_valid_merkle_branch(leaf, &[sibling, uncle], 5, root));
+		assert!(!is_valid_merkle_branch(leaf, &[sibling, uncle], 4, root));
+		assert!(!is_valid_merkle_branch(leaf, &[uncle, sibling], 5, root));
+	}
+
+	#[test]
+	fn is_valid_merkle_branch_fails_with_wrong_depth() {
+		let leaf = H256::repeat_byte(1);
+		let sibling = H256::repeat_byte(2);
+		let root = H256(sha2_256(&[sibling.as_bytes(), leaf.as_bytes()].concat()));
+
+		assert!(is_valid_merkle_branch(leaf, &[sibling], 3, root));
+		// The same branch does not prove a leaf deeper in the tree.
+		assert!(!is_valid_merkle_branch(leaf, &[sibling], 7, root));
+	}
 }
@@ -1,30 +1,44 @@
 use crate::{
+	config_preludes::MaxMessageHandlerWeight,
 	mock::{
-		new_test_ext, Balances, Bridge, RuntimeEvent, RuntimeOrigin, System, Test,
+		new_test_ext, Assets, Balances, Bridge, ConfigChangeDelay, HandledMessages,
+		MessageHandlerWeight, RuntimeEvent, RuntimeOrigin, System, Test, HANDLED_SENDER,
 		ROTATE_FUNCTION_ID, ROTATE_VK, STEP_FUNCTION_ID, STEP_VK,
 	},
-	state::Configuration,
+	state::{
+		split_message_id, ConfigChange, Configuration, ExecutionFee, PendingConfigChange,
+		ProofSystem, TransferDirection, UpdaterSelection,
+	},
 	storage_utils::MessageStatusEnum,
-	Broadcasters, ConfigurationStorage, Error, Event, ExecutionStateRoots, FunctionIds,
-	FunctionInput, FunctionOutput, FunctionProof, Head, Headers, MessageStatus, MockEnabled,
-	ProofOutputs, RotateVerificationKey, SP1VerificationKey, SourceChainFrozen,
-	StepVerificationKey, SyncCommitteeHashes, SyncCommitteePoseidons, Updater, ValidProof,
-	WhitelistedDomains,
+	weight_helper, BroadcasterLogTopics, Broadcasters, ConfigurationStorage, Error, Event,
+	ExecutionFees, ExecutionStateRoots, FailedSendMessageTxs, FulfilledSlots, FulfilledSlotsBounds,
+	FunctionIds, FunctionInput, FunctionOutput, FunctionProof, Head, Headers, LastUpdateBlock,
+	MessageStatus, MockEnabled, NextConfigChangeId, NextConfigChangeToApply, OutboundMessage,
+	OutboundMessageIds, OutboundMessageIdsBounds, OutboundMessageStatus, OutboundMessages,
+	PausedTransfers, PendingConfigChanges, PeriodPruningCursor, ProofOutputs, ProofSystems,
+	PrunedSlotsBelow, ReceiptsRootBranch, RegisteredAssets, RotateVerificationKey,
+	SP1VerificationKey, SourceChainFrozen, StepVerificationKey, SyncCommitteeHashes,
+	SyncCommitteePoseidons, Timestamps, TransferCaps, TransferUsage, Updater,
+	UpdaterSelectionStorage, Updaters, ValidProof, WhitelistedDomains, FAILED_SEND_MSG_ERRORS_ID,
+	RECEIPTS_ROOT_GINDEX,
 };
 use alloy_sol_types::SolValue;
+use avail_base::MemoryTemporaryStorage;
 use avail_core::data_proof::Message::FungibleToken;
-use avail_core::data_proof::{tx_uid, AddressedMessage, Message};
+use avail_core::data_proof::{tx_uid, AddressedMessage, Message, MessageType};
 use frame_support::{
 	assert_err, assert_ok,
-	traits::{fungible::Inspect, DefensiveTruncateFrom},
+	traits::{fungible::Inspect, DefensiveTruncateFrom, Hooks},
+	weights::Weight,
 	BoundedVec,
 };
 use frame_system::RawOrigin;
 use hex_literal::hex;
-use primitive_types::U256;
+use primitive_types::{H160, U256};
+use rlp::RlpStream;
 use sp1_sdk::SP1ProofWithPublicValues;
-use sp_core::{crypto::AccountId32, keccak_256, ByteArray};
-use sp_runtime::{testing::H256, traits::BadOrigin};
+use sp_core::{crypto::AccountId32, keccak_256, sha2_256, ByteArray};
+use sp_runtime::{testing::H256, traits::BadOrigin, DispatchError, Permill};
 
 const TEST_SENDER_VEC: [u8; 32] =
 	hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
@@ -573,6 +587,127 @@ fn test_execute_message_with_already_executed_message() {
 	});
 }
 
+fn setup_execute_batch() -> u64 {
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+		)),
+	);
+
+	let slot = 8581263;
+	ExecutionStateRoots::<Test>::set(
+		slot,
+		H256(hex!(
+			"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+		)),
+	);
+	slot
+}
+
+#[test]
+fn execute_batch_works() {
+	new_test_ext().execute_with(|| {
+		let slot = setup_execute_batch();
+		let balance_before = Balances::balance(&Bridge::account_id());
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+
+		// The second message was already executed by the first one.
+		let messages = BoundedVec::truncate_from(vec![
+			(message.clone(), get_valid_storage_proof()),
+			(message, get_valid_storage_proof()),
+		]);
+		let ok = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_account_proof(),
+			messages,
+		);
+		assert_ok!(ok);
+
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		assert_eq!(
+			balance_before.saturating_sub(1000000000000000000u128),
+			Balances::balance(&Bridge::account_id())
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::BatchExecuted {
+			slot,
+			executed: vec![0],
+			failed: vec![1],
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn execute_batch_does_not_accept_empty_batch() {
+	new_test_ext().execute_with(|| {
+		let slot = setup_execute_batch();
+
+		let err = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_account_proof(),
+			BoundedVec::new(),
+		);
+		assert_err!(err, Error::<Test>::InvalidBridgeInputs);
+	});
+}
+
+#[test]
+fn execute_batch_fails_with_faulty_account_proof() {
+	new_test_ext().execute_with(|| {
+		let slot = setup_execute_batch();
+		let messages =
+			BoundedVec::truncate_from(vec![(get_valid_message(), get_valid_storage_proof())]);
+
+		let err = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_invalid_account_proof(),
+			messages,
+		);
+		assert_err!(err, Error::<Test>::CannotGetStorageRoot);
+	});
+}
+
+#[test]
+fn execute_batch_skips_messages_from_other_domains() {
+	new_test_ext().execute_with(|| {
+		let slot = setup_execute_batch();
+		let balance_before = Balances::balance(&Bridge::account_id());
+		let mut other_domain_message = get_valid_message();
+		other_domain_message.origin_domain = 3;
+
+		let messages = BoundedVec::truncate_from(vec![
+			(get_valid_message(), get_valid_storage_proof()),
+			(other_domain_message, get_valid_storage_proof()),
+		]);
+		let ok = Bridge::execute_batch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_account_proof(),
+			messages,
+		);
+		assert_ok!(ok);
+
+		assert_eq!(
+			balance_before.saturating_sub(1000000000000000000u128),
+			Balances::balance(&Bridge::account_id())
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::BatchExecuted {
+			slot,
+			executed: vec![0],
+			failed: vec![1],
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
 #[test]
 fn test_execute_message_with_unsupported_domain() {
 	new_test_ext().execute_with(|| {
@@ -1152,6 +1287,269 @@ fn send_message_fungible_token_does_not_accept_zero_amount() {
 	});
 }
 
+#[test]
+fn send_message_failure_is_recorded() {
+	new_test_ext().execute_with(|| {
+		let who = AccountId32::new([7u8; 32]);
+		let message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 0,
+		};
+
+		let err =
+			Bridge::send_message(RuntimeOrigin::signed(who.clone()), message, H256::zero(), 2);
+		assert_err!(err, Error::<Test>::InvalidBridgeInputs);
+
+		let errors = MemoryTemporaryStorage::get::<
+			Vec<(u32, OutboundMessage<AccountId32>, DispatchError)>,
+		>(FAILED_SEND_MSG_ERRORS_ID)
+		.unwrap_or_default();
+		let failed = OutboundMessage {
+			from: who,
+			to: H256::zero(),
+			destination_domain: 2,
+			message_type: MessageType::FungibleToken,
+			block_number: 1,
+			status: OutboundMessageStatus::Failed,
+		};
+		assert!(errors.contains(&(0, failed, Error::<Test>::InvalidBridgeInputs.into())));
+		assert_eq!(OutboundMessages::<Test>::get(tx_uid(1, 0)), None);
+	});
+}
+
+#[test]
+fn send_message_failure_rolls_back_funds() {
+	new_test_ext().execute_with(|| {
+		use crate::BalanceOf;
+		use frame_support::traits::Currency;
+
+		let who: AccountId32 = TEST_SENDER_VEC.into();
+		Balances::make_free_balance_be(&who, BalanceOf::<Test>::max_value() / 2u128);
+		let balance_before = Balances::balance(&who);
+		let pot_before = Balances::balance(&Bridge::account_id());
+		// Fails after the funds are locked, as the message id cannot be derived.
+		frame_support::storage::unhashed::kill(sp_core::storage::well_known_keys::EXTRINSIC_INDEX);
+
+		let message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 100,
+		};
+		let err =
+			Bridge::send_message(RuntimeOrigin::signed(who.clone()), message, H256::zero(), 2);
+		assert_err!(err, Error::<Test>::CurrentMessageIdNotFound);
+		assert_eq!(Balances::balance(&who), balance_before);
+		assert_eq!(Balances::balance(&Bridge::account_id()), pot_before);
+	});
+}
+
+#[test]
+fn failed_send_message_txs_reports_failures() {
+	new_test_ext().execute_with(|| {
+		let who = AccountId32::new([7u8; 32]);
+		let error: DispatchError = Error::<Test>::InvalidBridgeInputs.into();
+		let failed = OutboundMessage {
+			from: who.clone(),
+			to: H256::zero(),
+			destination_domain: 2,
+			message_type: MessageType::FungibleToken,
+			block_number: 1,
+			status: OutboundMessageStatus::Failed,
+		};
+
+		Bridge::report_failed_send_messages(vec![3.into()], vec![(3, failed.clone(), error)]);
+
+		assert_eq!(FailedSendMessageTxs::<Test>::get(), vec![3]);
+		assert_eq!(Bridge::outbound_message(tx_uid(1, 3)), Some(failed));
+		let expected_event = RuntimeEvent::Bridge(Event::MessageSendFailed {
+			who,
+			tx_index: 3,
+			error,
+		});
+		System::assert_last_event(expected_event);
+
+		Bridge::on_initialize(2);
+		assert!(FailedSendMessageTxs::<Test>::get().is_empty());
+	});
+}
+
+fn send_arbitrary_messages(blocks: &[u64]) {
+	for block in blocks {
+		System::set_block_number(*block);
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from([0, 1, 2, 3].to_vec()));
+		assert_ok!(Bridge::send_message(
+			RuntimeOrigin::signed(TEST_SENDER_VEC.into()),
+			message,
+			ROTATE_FUNCTION_ID,
+			2
+		));
+	}
+}
+
+#[test]
+fn send_message_is_logged_as_outbound_message() {
+	new_test_ext().execute_with(|| {
+		send_arbitrary_messages(&[1]);
+
+		let expected = OutboundMessage {
+			from: TEST_SENDER_VEC.into(),
+			to: ROTATE_FUNCTION_ID,
+			destination_domain: 2,
+			message_type: MessageType::ArbitraryMessage,
+			block_number: 1,
+			status: OutboundMessageStatus::Sent,
+		};
+		assert_eq!(Bridge::outbound_message(tx_uid(1, 0)), Some(expected));
+		assert_eq!(OutboundMessageIds::<Test>::get(0), Some(tx_uid(1, 0)));
+		assert_eq!(OutboundMessageIdsBounds::<Test>::get(), (0, 1));
+	});
+}
+
+#[test]
+fn split_message_id_returns_block_and_tx_index() {
+	assert_eq!(split_message_id(tx_uid(1, 0)), (1, 0));
+	assert_eq!(split_message_id(tx_uid(7, 3)), (7, 3));
+	assert_eq!(
+		split_message_id(tx_uid(u32::MAX, u32::MAX)),
+		(u32::MAX, u32::MAX)
+	);
+}
+
+#[test]
+fn outbound_messages_are_paginated_by_message_id() {
+	new_test_ext().execute_with(|| {
+		send_arbitrary_messages(&[1, 2, 3]);
+
+		let ids = |messages: Vec<(u64, OutboundMessage<AccountId32>)>| {
+			messages.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+		};
+		assert_eq!(
+			ids(Bridge::outbound_messages(None, 2)),
+			vec![tx_uid(1, 0), tx_uid(2, 0)]
+		);
+		assert_eq!(
+			ids(Bridge::outbound_messages(Some(tx_uid(2, 0)), 10)),
+			vec![tx_uid(3, 0)]
+		);
+		assert!(Bridge::outbound_messages(Some(tx_uid(3, 0)), 10).is_empty());
+		assert!(Bridge::outbound_messages(Some(tx_uid(4, 0)), 10).is_empty());
+	});
+}
+
+const BRIDGED_TOKEN_ID: H256 = H256([1u8; 32]);
+const BRIDGED_ASSET_ID: u32 = 1;
+
+fn create_bridged_asset() {
+	assert_ok!(Assets::force_create(
+		RawOrigin::Root.into(),
+		BRIDGED_ASSET_ID,
+		TEST_SENDER_ACCOUNT,
+		true,
+		1
+	));
+	assert_ok!(Bridge::register_asset(
+		RawOrigin::Root.into(),
+		BRIDGED_TOKEN_ID,
+		BRIDGED_ASSET_ID
+	));
+}
+
+#[test]
+fn send_message_fungible_token_burns_registered_asset() {
+	new_test_ext().execute_with(|| {
+		create_bridged_asset();
+		let origin = RuntimeOrigin::signed(TEST_SENDER_ACCOUNT);
+		assert_ok!(Assets::mint(
+			origin.clone(),
+			BRIDGED_ASSET_ID,
+			TEST_SENDER_ACCOUNT,
+			1_000
+		));
+
+		let message = Message::FungibleToken {
+			asset_id: BRIDGED_TOKEN_ID,
+			amount: 100,
+		};
+		let ok = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_ok!(ok);
+		assert_eq!(Assets::balance(BRIDGED_ASSET_ID, TEST_SENDER_ACCOUNT), 900);
+		assert_eq!(Assets::total_supply(BRIDGED_ASSET_ID), 900);
+	});
+}
+
+#[test]
+fn send_message_fungible_token_does_not_accept_unregistered_asset() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let message = Message::FungibleToken {
+			asset_id: BRIDGED_TOKEN_ID,
+			amount: 100,
+		};
+
+		let err = Bridge::send_message(origin, message, ROTATE_FUNCTION_ID, 2);
+		assert_err!(err, Error::<Test>::AssetNotSupported);
+	});
+}
+
+#[test]
+fn register_asset_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::register_asset(RawOrigin::Root.into(), BRIDGED_TOKEN_ID, BRIDGED_ASSET_ID);
+		assert_ok!(ok);
+		assert_eq!(
+			RegisteredAssets::<Test>::get(BRIDGED_TOKEN_ID),
+			Some(BRIDGED_ASSET_ID)
+		);
+
+		let expected_event = RuntimeEvent::Bridge(Event::AssetRegistered {
+			token_id: BRIDGED_TOKEN_ID,
+			asset_id: BRIDGED_ASSET_ID,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn register_asset_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::register_asset(origin, BRIDGED_TOKEN_ID, BRIDGED_ASSET_ID);
+		assert_err!(err, BadOrigin);
+	});
+}
+
+#[test]
+fn register_asset_does_not_overwrite_registered_tokens() {
+	new_test_ext().execute_with(|| {
+		let err = Bridge::register_asset(RawOrigin::Root.into(), H256::zero(), BRIDGED_ASSET_ID);
+		assert_err!(err, Error::<Test>::AssetAlreadyRegistered);
+
+		create_bridged_asset();
+		let err = Bridge::register_asset(RawOrigin::Root.into(), BRIDGED_TOKEN_ID, 2);
+		assert_err!(err, Error::<Test>::AssetAlreadyRegistered);
+	});
+}
+
+#[test]
+fn deregister_asset_works_with_root() {
+	new_test_ext().execute_with(|| {
+		create_bridged_asset();
+
+		let ok = Bridge::deregister_asset(RawOrigin::Root.into(), BRIDGED_TOKEN_ID);
+		assert_ok!(ok);
+		assert_eq!(RegisteredAssets::<Test>::get(BRIDGED_TOKEN_ID), None);
+
+		let expected_event = RuntimeEvent::Bridge(Event::AssetDeregistered {
+			token_id: BRIDGED_TOKEN_ID,
+			asset_id: BRIDGED_ASSET_ID,
+		});
+		System::assert_last_event(expected_event);
+
+		let err = Bridge::deregister_asset(RawOrigin::Root.into(), BRIDGED_TOKEN_ID);
+		assert_err!(err, Error::<Test>::AssetNotSupported);
+	});
+}
+
 #[test]
 fn execute_arbitrary_message_works() {
 	new_test_ext().execute_with(|| {
@@ -1486,6 +1884,70 @@ fn test_fulfill_successfully() {
 	});
 }
 
+#[test]
+fn set_proof_system_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let vk = H256(SP1_VERIFICATION_KEY);
+		assert_eq!(ProofSystems::<Test>::get(vk), ProofSystem::Groth16Sp1);
+
+		let ok = Bridge::set_proof_system(RawOrigin::Root.into(), vk, ProofSystem::PlonkSp1);
+		assert_ok!(ok);
+
+		assert_eq!(ProofSystems::<Test>::get(vk), ProofSystem::PlonkSp1);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ProofSystemSet {
+			vk,
+			system: ProofSystem::PlonkSp1,
+		}));
+	});
+}
+
+#[test]
+fn set_proof_system_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let vk = H256(SP1_VERIFICATION_KEY);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::set_proof_system(origin, vk, ProofSystem::PlonkSp1);
+		assert_err!(err, BadOrigin);
+		assert_eq!(ProofSystems::<Test>::get(vk), ProofSystem::Groth16Sp1);
+	});
+}
+
+#[test]
+fn test_fulfill_with_plonk_proof_system_rejects_groth16_proof() {
+	new_test_ext().execute_with(|| {
+		let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(PROOF_FILE).unwrap();
+		let proof = sp1_proof_with_public_values.bytes();
+		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
+
+		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		ProofSystems::<Test>::insert(H256(SP1_VERIFICATION_KEY), ProofSystem::PlonkSp1);
+		let slots_per_period = 8192;
+		let last_slot = 7762303u64;
+		Head::<Test>::set(last_slot);
+		SyncCommitteeHashes::<Test>::set(
+			last_slot / slots_per_period,
+			H256(hex!(
+				"42839f47fdc746dc26ca1c2641262d5b997c7f0f9f78c4b2666a38785d872886"
+			)),
+		);
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period,
+			finality_threshold: 342,
+		});
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		let err = Bridge::fulfill(
+			RuntimeOrigin::signed(TEST_SENDER_VEC.into()),
+			BoundedVec::truncate_from(proof),
+			BoundedVec::truncate_from(public_inputs),
+		);
+
+		assert_err!(err, Error::<Test>::VerificationFailed);
+		assert_eq!(Head::<Test>::get(), last_slot);
+	});
+}
+
 #[test]
 fn test_fulfill_successfully_mock_enabled() {
 	new_test_ext().execute_with(|| {
@@ -1892,3 +2354,1309 @@ fn mock_enable_non_root() {
 		assert_eq!(MockEnabled::<Test>::get(), false);
 	});
 }
+
+fn fulfill_slots(slots: &[u64]) {
+	for (index, slot) in slots.iter().enumerate() {
+		Headers::<Test>::set(*slot, H256::random());
+		Timestamps::<Test>::set(*slot, 1);
+		ExecutionStateRoots::<Test>::set(*slot, H256::random());
+		FulfilledSlots::<Test>::insert(index as u64, *slot);
+	}
+	FulfilledSlotsBounds::<Test>::set((0, slots.len() as u64));
+}
+
+fn set_sync_committees(periods: u64) {
+	ConfigurationStorage::<Test>::set(Configuration {
+		slots_per_period: 8192,
+		finality_threshold: 342,
+	});
+	for period in 0..periods {
+		SyncCommitteePoseidons::<Test>::set(period, U256::one());
+		SyncCommitteeHashes::<Test>::set(period, H256::random());
+	}
+}
+
+#[test]
+fn on_idle_prunes_slots_out_of_retention() {
+	new_test_ext().execute_with(|| {
+		fulfill_slots(&[100, 200]);
+		// Slot retention of the mock is 8192 slots.
+		Head::<Test>::set(100 + 8192 + 1);
+
+		Bridge::on_idle(1, Weight::MAX);
+
+		assert_eq!(Headers::<Test>::get(100), H256::zero());
+		assert_eq!(Timestamps::<Test>::get(100), 0);
+		assert_eq!(ExecutionStateRoots::<Test>::get(100), H256::zero());
+		assert_ne!(Headers::<Test>::get(200), H256::zero());
+		assert_ne!(ExecutionStateRoots::<Test>::get(200), H256::zero());
+		assert_eq!(FulfilledSlots::<Test>::get(0), None);
+		assert_eq!(FulfilledSlots::<Test>::get(1), Some(200));
+		assert_eq!(FulfilledSlotsBounds::<Test>::get(), (1, 2));
+		assert_eq!(PrunedSlotsBelow::<Test>::get(), 101);
+	});
+}
+
+#[test]
+fn on_idle_prunes_periods_out_of_retention() {
+	new_test_ext().execute_with(|| {
+		set_sync_committees(10);
+		// Period retention of the mock is 4 periods.
+		Head::<Test>::set(10 * 8192);
+
+		Bridge::on_idle(1, Weight::MAX);
+
+		for period in 0..6 {
+			assert_eq!(SyncCommitteePoseidons::<Test>::get(period), U256::zero());
+			assert_eq!(SyncCommitteeHashes::<Test>::get(period), H256::zero());
+		}
+		for period in 6..10 {
+			assert_eq!(SyncCommitteePoseidons::<Test>::get(period), U256::one());
+			assert_ne!(SyncCommitteeHashes::<Test>::get(period), H256::zero());
+		}
+		assert_eq!(PeriodPruningCursor::<Test>::get(), 6);
+	});
+}
+
+#[test]
+fn on_idle_prunes_outbound_messages_out_of_retention() {
+	new_test_ext().execute_with(|| {
+		send_arbitrary_messages(&[1, 2]);
+		// Outbound message retention of the mock is 50_400 blocks.
+		System::set_block_number(1 + 50_400 + 1);
+
+		Bridge::on_idle(1 + 50_400 + 1, Weight::MAX);
+
+		assert_eq!(OutboundMessages::<Test>::get(tx_uid(1, 0)), None);
+		assert_eq!(OutboundMessageIds::<Test>::get(0), None);
+		assert!(Bridge::outbound_message(tx_uid(2, 0)).is_some());
+		assert_eq!(OutboundMessageIdsBounds::<Test>::get(), (1, 2));
+	});
+}
+
+#[test]
+fn on_idle_does_not_prune_without_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		fulfill_slots(&[100]);
+		set_sync_committees(10);
+		Head::<Test>::set(10 * 8192);
+
+		let weight = Bridge::on_idle(1, Weight::zero());
+
+		assert_eq!(weight, Weight::zero());
+		assert_ne!(Headers::<Test>::get(100), H256::zero());
+		assert_ne!(SyncCommitteeHashes::<Test>::get(0), H256::zero());
+		assert_eq!(FulfilledSlotsBounds::<Test>::get(), (0, 1));
+		assert_eq!(PeriodPruningCursor::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn test_execute_message_with_pruned_slot() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		PrunedSlotsBelow::<Test>::set(slot + 1);
+
+		let error = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_message(),
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		);
+
+		assert_err!(error, Error::<Test>::SlotPruned);
+	});
+}
+
+#[test]
+fn prune_light_client_state_works_with_root() {
+	new_test_ext().execute_with(|| {
+		set_sync_committees(10);
+		Headers::<Test>::set(100, H256::random());
+		ExecutionStateRoots::<Test>::set(100, H256::random());
+		Head::<Test>::set(10 * 8192);
+
+		let slots = BoundedVec::truncate_from(vec![100]);
+		let periods = BoundedVec::truncate_from(vec![0, 1]);
+		let ok = Bridge::prune_light_client_state(RawOrigin::Root.into(), slots, periods);
+		assert_ok!(ok);
+
+		assert_eq!(Headers::<Test>::get(100), H256::zero());
+		assert_eq!(ExecutionStateRoots::<Test>::get(100), H256::zero());
+		assert_eq!(PrunedSlotsBelow::<Test>::get(), 0);
+		assert_eq!(SyncCommitteeHashes::<Test>::get(0), H256::zero());
+		assert_eq!(SyncCommitteeHashes::<Test>::get(1), H256::zero());
+		assert_ne!(SyncCommitteeHashes::<Test>::get(2), H256::zero());
+
+		let expected_event = RuntimeEvent::Bridge(Event::LightClientStatePruned {
+			slots: 1,
+			periods: 2,
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn prune_light_client_state_does_not_prune_earlier_slots() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		Head::<Test>::set(slot + 2 * 8192);
+
+		let slots = BoundedVec::truncate_from(vec![slot + 1]);
+		let ok = Bridge::prune_light_client_state(RawOrigin::Root.into(), slots, BoundedVec::new());
+		assert_ok!(ok);
+
+		let ok = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_message(),
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		);
+		assert_ok!(ok);
+	});
+}
+
+#[test]
+fn prune_light_client_state_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let ok = Bridge::prune_light_client_state(origin, BoundedVec::new(), BoundedVec::new());
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn prune_light_client_state_keeps_retained_state() {
+	new_test_ext().execute_with(|| {
+		set_sync_committees(10);
+		Headers::<Test>::set(80_000, H256::random());
+		Head::<Test>::set(10 * 8192);
+
+		let slots = BoundedVec::truncate_from(vec![80_000]);
+		let ok = Bridge::prune_light_client_state(RawOrigin::Root.into(), slots, BoundedVec::new());
+		assert_err!(ok, Error::<Test>::StateWithinRetention);
+
+		let periods = BoundedVec::truncate_from(vec![6]);
+		let ok =
+			Bridge::prune_light_client_state(RawOrigin::Root.into(), BoundedVec::new(), periods);
+		assert_err!(ok, Error::<Test>::StateWithinRetention);
+
+		assert_ne!(Headers::<Test>::get(80_000), H256::zero());
+		assert_ne!(SyncCommitteeHashes::<Test>::get(6), H256::zero());
+		assert_eq!(PrunedSlotsBelow::<Test>::get(), 0);
+	});
+}
+
+fn send_fungible_token(amount: u128) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	use crate::BalanceOf;
+	use frame_support::traits::Currency;
+
+	Balances::make_free_balance_be(
+		&TEST_SENDER_VEC.into(),
+		BalanceOf::<Test>::max_value() / 2u128,
+	);
+	let message = Message::FungibleToken {
+		asset_id: H256::zero(),
+		amount,
+	};
+
+	Bridge::send_message(
+		RuntimeOrigin::signed(TEST_SENDER_VEC.into()),
+		message,
+		ROTATE_FUNCTION_ID,
+		2,
+	)
+}
+
+#[test]
+fn set_transfer_cap_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok = Bridge::set_transfer_cap(
+			RawOrigin::Root.into(),
+			2,
+			TransferDirection::Outbound,
+			H256::zero(),
+			Some(100),
+		);
+		assert_ok!(ok);
+		assert_eq!(
+			TransferCaps::<Test>::get((2, TransferDirection::Outbound, H256::zero())),
+			Some(100)
+		);
+		assert_eq!(
+			TransferCaps::<Test>::get((2, TransferDirection::Inbound, H256::zero())),
+			None
+		);
+
+		let expected_event = RuntimeEvent::Bridge(Event::TransferCapSet {
+			domain: 2,
+			direction: TransferDirection::Outbound,
+			token_id: H256::zero(),
+			cap: Some(100),
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn set_transfer_cap_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let ok = Bridge::set_transfer_cap(
+			origin,
+			2,
+			TransferDirection::Outbound,
+			H256::zero(),
+			Some(100),
+		);
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn send_message_fungible_token_pauses_when_cap_is_exceeded() {
+	new_test_ext().execute_with(|| {
+		TransferCaps::<Test>::set((2, TransferDirection::Outbound, H256::zero()), Some(150));
+		let pot_before = Balances::balance(&Bridge::account_id());
+
+		assert_ok!(send_fungible_token(100));
+		assert_eq!(
+			TransferUsage::<Test>::get((2, TransferDirection::Outbound, H256::zero())),
+			(0, 100)
+		);
+
+		// The transfer is not sent, but transfers of the domain are paused.
+		assert_ok!(send_fungible_token(100));
+		assert!(PausedTransfers::<Test>::get((
+			2,
+			TransferDirection::Outbound,
+			H256::zero()
+		)));
+		assert_eq!(Balances::balance(&Bridge::account_id()), pot_before + 100);
+		let expected_event = RuntimeEvent::Bridge(Event::TransfersPaused {
+			domain: 2,
+			direction: TransferDirection::Outbound,
+			token_id: H256::zero(),
+		});
+		System::assert_last_event(expected_event);
+
+		let err = send_fungible_token(10);
+		assert_err!(err, Error::<Test>::TransfersPaused);
+		assert!(!PausedTransfers::<Test>::get((
+			2,
+			TransferDirection::Inbound,
+			H256::zero()
+		)));
+	});
+}
+
+#[test]
+fn send_message_fungible_token_cap_resets_every_period() {
+	new_test_ext().execute_with(|| {
+		TransferCaps::<Test>::set((2, TransferDirection::Outbound, H256::zero()), Some(100));
+		assert_ok!(send_fungible_token(100));
+
+		// Transfer cap period of the mock is 7200 blocks.
+		System::set_block_number(7200);
+		assert_ok!(send_fungible_token(100));
+		assert!(!PausedTransfers::<Test>::get((
+			2,
+			TransferDirection::Outbound,
+			H256::zero()
+		)));
+		assert_eq!(
+			TransferUsage::<Test>::get((2, TransferDirection::Outbound, H256::zero())),
+			(1, 100)
+		);
+	});
+}
+
+#[test]
+fn send_message_fungible_token_cap_is_per_token() {
+	new_test_ext().execute_with(|| {
+		let other_token = H256::repeat_byte(1);
+		TransferCaps::<Test>::set((2, TransferDirection::Outbound, other_token), Some(10));
+		PausedTransfers::<Test>::insert((2, TransferDirection::Outbound, other_token), true);
+
+		assert_ok!(send_fungible_token(100));
+		assert!(!PausedTransfers::<Test>::get((
+			2,
+			TransferDirection::Outbound,
+			H256::zero()
+		)));
+		assert_eq!(
+			TransferUsage::<Test>::get((2, TransferDirection::Outbound, other_token)),
+			(0, 0)
+		);
+	});
+}
+
+#[test]
+fn execute_fungible_token_pauses_when_cap_is_exceeded() {
+	new_test_ext().execute_with(|| {
+		let balance_before = Balances::balance(&Bridge::account_id());
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		TransferCaps::<Test>::set((2, TransferDirection::Inbound, H256::zero()), Some(1));
+
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(message.clone().abi_encode().as_slice()));
+		// amount in message 1000000000000000000
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		);
+
+		// The message is not executed and can be executed once transfers are resumed.
+		assert_ok!(result);
+		assert!(PausedTransfers::<Test>::get((
+			2,
+			TransferDirection::Inbound,
+			H256::zero()
+		)));
+		assert_eq!(Balances::balance(&Bridge::account_id()), balance_before);
+		assert_ne!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::TransfersPaused {
+			domain: 2,
+			direction: TransferDirection::Inbound,
+			token_id: H256::zero(),
+		});
+		System::assert_last_event(expected_event);
+	});
+}
+
+#[test]
+fn resume_transfers_works_with_root() {
+	new_test_ext().execute_with(|| {
+		TransferCaps::<Test>::set((2, TransferDirection::Outbound, H256::zero()), Some(100));
+		PausedTransfers::<Test>::insert((2, TransferDirection::Outbound, H256::zero()), true);
+		TransferUsage::<Test>::insert((2, TransferDirection::Outbound, H256::zero()), (0, 100));
+
+		let ok = Bridge::resume_transfers(
+			RawOrigin::Root.into(),
+			2,
+			TransferDirection::Outbound,
+			H256::zero(),
+		);
+		assert_ok!(ok);
+		assert!(!PausedTransfers::<Test>::get((
+			2,
+			TransferDirection::Outbound,
+			H256::zero()
+		)));
+		assert_eq!(
+			TransferUsage::<Test>::get((2, TransferDirection::Outbound, H256::zero())),
+			(0, 0)
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::TransfersResumed {
+			domain: 2,
+			direction: TransferDirection::Outbound,
+			token_id: H256::zero(),
+		});
+		System::assert_last_event(expected_event);
+
+		assert_ok!(send_fungible_token(100));
+	});
+}
+
+#[test]
+fn resume_transfers_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		PausedTransfers::<Test>::insert((2, TransferDirection::Outbound, H256::zero()), true);
+
+		let ok = Bridge::resume_transfers(origin, 2, TransferDirection::Outbound, H256::zero());
+		assert_err!(ok, BadOrigin);
+		assert!(PausedTransfers::<Test>::get((
+			2,
+			TransferDirection::Outbound,
+			H256::zero()
+		)));
+	});
+}
+
+#[test]
+fn set_execution_fee_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let fee = ExecutionFee {
+			fungible_token: Permill::from_percent(1),
+		};
+
+		let ok = Bridge::set_execution_fee(RawOrigin::Root.into(), 2, Some(fee));
+		assert_ok!(ok);
+		assert_eq!(ExecutionFees::<Test>::get(2), fee);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ExecutionFeeSet {
+			domain: 2,
+			fee: Some(fee),
+		}));
+
+		let ok = Bridge::set_execution_fee(RawOrigin::Root.into(), 2, None);
+		assert_ok!(ok);
+		assert!(!ExecutionFees::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn set_execution_fee_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let ok = Bridge::set_execution_fee(origin, 2, Some(ExecutionFee::default()));
+		assert_err!(ok, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_fungible_token_pays_execution_fee() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		let slot = 8581263;
+		ExecutionStateRoots::<Test>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		ExecutionFees::<Test>::insert(
+			2,
+			ExecutionFee {
+				fungible_token: Permill::from_percent(1),
+			},
+		);
+		let message = get_valid_message();
+		let recipient = AccountId32::from_slice(message.to.as_bytes()).unwrap();
+
+		// amount in message 1000000000000000000
+		let ok = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_account_proof(),
+			get_valid_storage_proof(),
+		);
+		assert_ok!(ok);
+
+		assert_eq!(
+			Balances::balance(&TEST_SENDER_ACCOUNT),
+			10_000_000_000_000_000
+		);
+		assert_eq!(Balances::balance(&recipient), 990_000_000_000_000_000);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::ExecutionFeePaid {
+			message_id: message.id,
+			relayer: TEST_SENDER_ACCOUNT,
+			fee: 10_000_000_000_000_000,
+		}));
+	});
+}
+
+#[test]
+fn execute_arbitrary_message_does_not_pay_execution_fee() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		ExecutionFees::<Test>::insert(
+			2,
+			ExecutionFee {
+				fungible_token: Permill::from_percent(1),
+			},
+		);
+
+		let ok = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			get_valid_amb_message(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_ok!(ok);
+
+		assert_eq!(Balances::balance(&TEST_SENDER_ACCOUNT), 0);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Bridge(Event::ExecutionFeePaid { .. })
+		)));
+	});
+}
+
+#[test]
+fn execute_arbitrary_message_is_dispatched_to_handler() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		let message = get_valid_amb_message();
+		let Message::ArbitraryMessage(ref data) = message.message else {
+			unreachable!()
+		};
+
+		let post_info = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		)
+		.unwrap();
+
+		assert_eq!(
+			HandledMessages::get(),
+			vec![(2, HANDLED_SENDER, message.to, data.to_vec())]
+		);
+		let unused_weight = MaxMessageHandlerWeight::get() - MessageHandlerWeight::get();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(weight_helper::execute::<Test>(&message.message) - unused_weight)
+		);
+	});
+}
+
+#[test]
+fn execute_arbitrary_message_fails_when_handler_exceeds_weight() {
+	new_test_ext().execute_with(|| {
+		Broadcasters::<Test>::set(
+			2,
+			H256(hex!(
+				"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+			)),
+		);
+		let slot = 5085118;
+		ExecutionStateRoots::<Test>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		MessageHandlerWeight::set(MaxMessageHandlerWeight::get().add_ref_time(1));
+		let message = get_valid_amb_message();
+
+		let err = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			get_valid_amb_account_proof(),
+			get_valid_amb_storage_proof(),
+		);
+		assert_err!(err, Error::<Test>::MessageHandlerWeightExceeded);
+
+		let message_root = H256(keccak_256(message.abi_encode().as_slice()));
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::NotExecuted
+		);
+	});
+}
+
+const MESSAGE_LOG_TOPIC: H256 = H256([1u8; 32]);
+const BROADCASTER_ADDRESS: H160 = H160(hex!("Aa8c1bFC413e00884A7ac991851686D27b387997"));
+
+/// Builds a receipts trie holding a single EIP-1559 receipt, with one log of `address` and `topics`,
+/// at the transaction index 0.
+fn receipt_proof(address: H160, topics: &[H256]) -> (H256, ValidProof) {
+	let mut log = RlpStream::new_list(3);
+	log.append(&address.as_bytes().to_vec());
+	log.begin_list(topics.len());
+	for topic in topics {
+		log.append(&topic.as_bytes().to_vec());
+	}
+	log.append(&Vec::<u8>::new());
+
+	let mut receipt = RlpStream::new_list(4);
+	receipt
+		.append(&1u8)
+		.append(&21_000u64)
+		.append(&vec![0u8; 256]);
+	receipt.begin_list(1).append_raw(&log.out(), 1);
+	let receipt = [vec![2u8], receipt.out().to_vec()].concat();
+
+	// A trie holding a single receipt is a leaf with the whole, hex prefixed, key.
+	let mut path = vec![0x20];
+	path.extend_from_slice(&rlp::encode(&0u32));
+	let mut leaf = RlpStream::new_list(2);
+	leaf.append(&path).append(&receipt);
+	let leaf = leaf.out().to_vec();
+
+	(
+		H256(keccak_256(&leaf)),
+		BoundedVec::truncate_from(vec![BoundedVec::truncate_from(leaf)]),
+	)
+}
+
+/// Builds a branch of `receipts_root` and the header root it proves it against.
+fn receipts_root_branch(receipts_root: H256) -> (H256, ReceiptsRootBranch) {
+	let branch: Vec<H256> = (0..12).map(H256::repeat_byte).collect();
+	let mut header = receipts_root;
+	let mut index = RECEIPTS_ROOT_GINDEX;
+	for sibling in &branch {
+		header = if index % 2 == 1 {
+			H256(sha2_256(&[sibling.as_bytes(), header.as_bytes()].concat()))
+		} else {
+			H256(sha2_256(&[header.as_bytes(), sibling.as_bytes()].concat()))
+		};
+		index /= 2;
+	}
+
+	(header, BoundedVec::truncate_from(branch))
+}
+
+/// Stores the broadcaster of `message`, its log topic and the header of `slot` proving `receipts_root`.
+fn set_receipt_header(slot: u64, receipts_root: H256) -> ReceiptsRootBranch {
+	Broadcasters::<Test>::set(
+		2,
+		H256(hex!(
+			"Aa8c1bFC413e00884A7ac991851686D27b387997000000000000000000000000"
+		)),
+	);
+	BroadcasterLogTopics::<Test>::set(2, Some(MESSAGE_LOG_TOPIC));
+	let (header, branch) = receipts_root_branch(receipts_root);
+	Headers::<Test>::set(slot, header);
+
+	branch
+}
+
+#[test]
+fn execute_via_receipt_works() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		let (receipts_root, receipt_proof) =
+			receipt_proof(BROADCASTER_ADDRESS, &[MESSAGE_LOG_TOPIC, message_root]);
+		let branch = set_receipt_header(slot, receipts_root);
+		let recipient = AccountId32::from_slice(message.to.as_bytes()).unwrap();
+
+		let ok = Bridge::execute_via_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			receipts_root,
+			branch,
+			0,
+			0,
+			receipt_proof,
+		);
+		assert_ok!(ok);
+
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::ExecutionSucceeded
+		);
+		assert_eq!(Balances::balance(&recipient), 1_000_000_000_000_000_000);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+		}));
+	});
+}
+
+#[test]
+fn execute_via_receipt_fails_with_invalid_receipts_root() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		let (receipts_root, receipt_proof) =
+			receipt_proof(BROADCASTER_ADDRESS, &[MESSAGE_LOG_TOPIC, message_root]);
+		let branch = set_receipt_header(slot, receipts_root);
+		Headers::<Test>::set(slot, H256::repeat_byte(1));
+
+		let err = Bridge::execute_via_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			receipts_root,
+			branch,
+			0,
+			0,
+			receipt_proof,
+		);
+		assert_err!(err, Error::<Test>::InvalidReceiptsRoot);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::NotExecuted
+		);
+	});
+}
+
+#[test]
+fn execute_via_receipt_fails_with_foreign_log() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		let (receipts_root, receipt_proof) =
+			receipt_proof(H160::repeat_byte(1), &[MESSAGE_LOG_TOPIC, message_root]);
+		let branch = set_receipt_header(slot, receipts_root);
+
+		let err = Bridge::execute_via_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message.clone(),
+			receipts_root,
+			branch,
+			0,
+			0,
+			receipt_proof,
+		);
+		assert_err!(err, Error::<Test>::InvalidMessageLog);
+
+		// The log of another message does not prove this one either.
+		let (receipts_root, receipt_proof) =
+			receipt_proof(BROADCASTER_ADDRESS, &[MESSAGE_LOG_TOPIC, H256::zero()]);
+		let branch = set_receipt_header(slot, receipts_root);
+		let err = Bridge::execute_via_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			receipts_root,
+			branch,
+			0,
+			0,
+			receipt_proof,
+		);
+		assert_err!(err, Error::<Test>::InvalidMessageLog);
+	});
+}
+
+#[test]
+fn execute_via_receipt_fails_without_log_topic() {
+	new_test_ext().execute_with(|| {
+		let slot = 8581263;
+		let message = get_valid_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		let (receipts_root, receipt_proof) =
+			receipt_proof(BROADCASTER_ADDRESS, &[MESSAGE_LOG_TOPIC, message_root]);
+		let branch = set_receipt_header(slot, receipts_root);
+		BroadcasterLogTopics::<Test>::remove(2);
+
+		let err = Bridge::execute_via_receipt(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			slot,
+			message,
+			receipts_root,
+			branch,
+			0,
+			0,
+			receipt_proof,
+		);
+		assert_err!(err, Error::<Test>::BroadcasterLogTopicNotSet);
+	});
+}
+
+#[test]
+fn set_broadcaster_log_topic_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let ok =
+			Bridge::set_broadcaster_log_topic(RawOrigin::Root.into(), 2, Some(MESSAGE_LOG_TOPIC));
+		assert_ok!(ok);
+		assert_eq!(
+			BroadcasterLogTopics::<Test>::get(2),
+			Some(MESSAGE_LOG_TOPIC)
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::BroadcasterLogTopicSet {
+			domain: 2,
+			topic: Some(MESSAGE_LOG_TOPIC),
+		}));
+
+		let ok = Bridge::set_broadcaster_log_topic(RawOrigin::Root.into(), 2, None);
+		assert_ok!(ok);
+		assert_eq!(BroadcasterLogTopics::<Test>::get(2), None);
+	});
+}
+
+#[test]
+fn set_broadcaster_log_topic_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::set_broadcaster_log_topic(origin, 2, Some(MESSAGE_LOG_TOPIC));
+		assert_err!(err, BadOrigin);
+		assert_eq!(BroadcasterLogTopics::<Test>::get(2), None);
+	});
+}
+
+const TEST_UPDATER: H256 = H256([3u8; 32]);
+
+fn mock_fulfill_as(
+	updater: H256,
+	public_values: Vec<u8>,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+	MockEnabled::<Test>::set(true);
+	let origin = RuntimeOrigin::signed(AccountId32::new(updater.0));
+	Bridge::mock_fulfill(origin, BoundedVec::truncate_from(public_values))
+}
+
+#[test]
+fn add_updater_works_with_root() {
+	new_test_ext().execute_with(|| {
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		let ok = Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER);
+		assert_ok!(ok);
+		assert_eq!(Updaters::<Test>::get().to_vec(), vec![TEST_UPDATER]);
+		assert_eq!(
+			Bridge::authorized_updaters(),
+			vec![H256(TEST_SENDER_VEC), TEST_UPDATER]
+		);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::UpdaterAdded {
+			updater: TEST_UPDATER,
+		}));
+	});
+}
+
+#[test]
+fn add_updater_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::add_updater(origin, TEST_UPDATER);
+		assert_err!(err, BadOrigin);
+		assert!(Updaters::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn add_updater_fails_when_already_authorized() {
+	new_test_ext().execute_with(|| {
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		assert_ok!(Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER));
+
+		let err = Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER);
+		assert_err!(err, Error::<Test>::UpdaterAlreadyAuthorized);
+		let err = Bridge::add_updater(RawOrigin::Root.into(), H256(TEST_SENDER_VEC));
+		assert_err!(err, Error::<Test>::UpdaterAlreadyAuthorized);
+	});
+}
+
+#[test]
+fn add_updater_fails_when_full() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=8 {
+			let updater = H256::from_low_u64_be(i);
+			assert_ok!(Bridge::add_updater(RawOrigin::Root.into(), updater));
+		}
+
+		let err = Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER);
+		assert_err!(err, Error::<Test>::TooManyUpdaters);
+	});
+}
+
+#[test]
+fn remove_updater_works_with_root() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER));
+
+		let ok = Bridge::remove_updater(RawOrigin::Root.into(), TEST_UPDATER);
+		assert_ok!(ok);
+		assert!(Updaters::<Test>::get().is_empty());
+		System::assert_last_event(RuntimeEvent::Bridge(Event::UpdaterRemoved {
+			updater: TEST_UPDATER,
+		}));
+
+		let err = mock_fulfill_as(TEST_UPDATER, vec![]);
+		assert_err!(err, Error::<Test>::UpdaterMisMatch);
+	});
+}
+
+#[test]
+fn remove_updater_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER));
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::remove_updater(origin, TEST_UPDATER);
+		assert_err!(err, BadOrigin);
+		assert_eq!(Updaters::<Test>::get().to_vec(), vec![TEST_UPDATER]);
+	});
+}
+
+#[test]
+fn remove_updater_fails_when_not_found() {
+	new_test_ext().execute_with(|| {
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+
+		let err = Bridge::remove_updater(RawOrigin::Root.into(), TEST_UPDATER);
+		assert_err!(err, Error::<Test>::UpdaterNotFound);
+		// The primary updater is replaced with `set_updater`, not removed.
+		let err = Bridge::remove_updater(RawOrigin::Root.into(), H256(TEST_SENDER_VEC));
+		assert_err!(err, Error::<Test>::UpdaterNotFound);
+	});
+}
+
+#[test]
+fn set_updater_selection_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let selection = UpdaterSelection::RoundRobin { turn_length: 10 };
+
+		let ok = Bridge::set_updater_selection(RawOrigin::Root.into(), selection);
+		assert_ok!(ok);
+		assert_eq!(UpdaterSelectionStorage::<Test>::get(), selection);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::UpdaterSelectionSet {
+			selection,
+		}));
+	});
+}
+
+#[test]
+fn set_updater_selection_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+		let selection = UpdaterSelection::RoundRobin { turn_length: 10 };
+
+		let err = Bridge::set_updater_selection(origin, selection);
+		assert_err!(err, BadOrigin);
+		assert_eq!(
+			UpdaterSelectionStorage::<Test>::get(),
+			UpdaterSelection::FirstCome
+		);
+	});
+}
+
+#[test]
+fn set_updater_selection_rejects_empty_turns() {
+	new_test_ext().execute_with(|| {
+		let selection = UpdaterSelection::RoundRobin { turn_length: 0 };
+
+		let err = Bridge::set_updater_selection(RawOrigin::Root.into(), selection);
+		assert_err!(err, Error::<Test>::InvalidUpdaterTurnLength);
+	});
+}
+
+#[test]
+fn added_updater_can_fulfill() {
+	new_test_ext().execute_with(|| {
+		let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(PROOF_FILE).unwrap();
+		let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
+		SP1VerificationKey::<Test>::set(H256(SP1_VERIFICATION_KEY));
+		let slots_per_period = 8192;
+		let last_slot = 7762302u64;
+		Head::<Test>::set(last_slot);
+		SyncCommitteeHashes::<Test>::set(
+			last_slot / slots_per_period,
+			H256(hex!(
+				"42839f47fdc746dc26ca1c2641262d5b997c7f0f9f78c4b2666a38785d872886"
+			)),
+		);
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period,
+			finality_threshold: 342,
+		});
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		assert_ok!(Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER));
+		System::set_block_number(5);
+
+		assert_ok!(mock_fulfill_as(TEST_UPDATER, public_inputs));
+		assert_eq!(LastUpdateBlock::<Test>::get(), Some(5));
+
+		let err = mock_fulfill_as(H256([4u8; 32]), vec![]);
+		assert_err!(err, Error::<Test>::UpdaterMisMatch);
+	});
+}
+
+#[test]
+fn round_robin_updaters_take_turns() {
+	new_test_ext().execute_with(|| {
+		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		assert_ok!(Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER));
+		let selection = UpdaterSelection::RoundRobin { turn_length: 10 };
+		assert_ok!(Bridge::set_updater_selection(
+			RawOrigin::Root.into(),
+			selection
+		));
+
+		// Blocks 0 to 9 are the turn of the primary updater, 10 to 19 of the added one.
+		System::set_block_number(9);
+		let err = mock_fulfill_as(TEST_UPDATER, vec![]);
+		assert_err!(err, Error::<Test>::NotUpdaterTurn);
+		let err = mock_fulfill_as(H256(TEST_SENDER_VEC), vec![]);
+		assert_err!(err, Error::<Test>::CannotDecodePublicValue);
+
+		System::set_block_number(10);
+		let err = mock_fulfill_as(H256(TEST_SENDER_VEC), vec![]);
+		assert_err!(err, Error::<Test>::NotUpdaterTurn);
+		let err = mock_fulfill_as(TEST_UPDATER, vec![]);
+		assert_err!(err, Error::<Test>::CannotDecodePublicValue);
+	});
+}
+
+#[test]
+fn on_initialize_reports_stalled_updates() {
+	new_test_ext().execute_with(|| {
+		// Nothing is reported before the first update.
+		Bridge::on_initialize(601);
+		assert!(System::events().is_empty());
+
+		LastUpdateBlock::<Test>::set(Some(1));
+		Bridge::on_initialize(600);
+		assert!(System::events().is_empty());
+
+		let stalled = RuntimeEvent::Bridge(Event::UpdatesStalled { last_update: 1 });
+		Bridge::on_initialize(601);
+		System::assert_last_event(stalled);
+		Bridge::on_initialize(602);
+		assert_eq!(System::events().len(), 1);
+		Bridge::on_initialize(1201);
+		assert_eq!(System::events().len(), 2);
+	});
+}
+
+const TEST_BROADCASTER: H256 = H256([5u8; 32]);
+
+fn announce_broadcaster_with_delay() {
+	ConfigChangeDelay::set(10);
+	let ok = Bridge::set_broadcaster(RawOrigin::Root.into(), 2, TEST_BROADCASTER);
+	assert_ok!(ok);
+}
+
+#[test]
+fn set_broadcaster_is_announced_with_delay() {
+	new_test_ext().execute_with(|| {
+		announce_broadcaster_with_delay();
+
+		let change = ConfigChange::Broadcaster {
+			domain: 2,
+			broadcaster: TEST_BROADCASTER,
+		};
+		assert_eq!(Broadcasters::<Test>::get(2), H256::zero());
+		assert_eq!(
+			PendingConfigChanges::<Test>::get(0),
+			Some(PendingConfigChange {
+				change: change.clone(),
+				applicable_at: 11,
+			})
+		);
+		assert_eq!(NextConfigChangeId::<Test>::get(), 1);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ConfigChangeAnnounced {
+			id: 0,
+			change,
+			applicable_at: 11,
+		}));
+	});
+}
+
+#[test]
+fn apply_config_change_works_after_delay() {
+	new_test_ext().execute_with(|| {
+		announce_broadcaster_with_delay();
+		System::set_block_number(11);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let ok = Bridge::apply_config_change(origin, 0);
+		assert_ok!(ok);
+		assert_eq!(Broadcasters::<Test>::get(2), TEST_BROADCASTER);
+		assert_eq!(PendingConfigChanges::<Test>::get(0), None);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::BroadcasterUpdated {
+			old: H256::zero(),
+			new: TEST_BROADCASTER,
+			domain: 2,
+		}));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ConfigChangeApplied { id: 0 }));
+	});
+}
+
+#[test]
+fn add_updater_is_announced_with_delay() {
+	new_test_ext().execute_with(|| {
+		ConfigChangeDelay::set(10);
+
+		let ok = Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER);
+		assert_ok!(ok);
+		assert!(Updaters::<Test>::get().is_empty());
+		assert_eq!(
+			PendingConfigChanges::<Test>::get(0),
+			Some(PendingConfigChange {
+				change: ConfigChange::AddUpdater(TEST_UPDATER),
+				applicable_at: 11,
+			})
+		);
+	});
+}
+
+#[test]
+fn set_broadcaster_log_topic_is_announced_with_delay() {
+	new_test_ext().execute_with(|| {
+		ConfigChangeDelay::set(10);
+
+		let ok =
+			Bridge::set_broadcaster_log_topic(RawOrigin::Root.into(), 2, Some(MESSAGE_LOG_TOPIC));
+		assert_ok!(ok);
+		assert_eq!(BroadcasterLogTopics::<Test>::get(2), None);
+		assert_eq!(
+			PendingConfigChanges::<Test>::get(0),
+			Some(PendingConfigChange {
+				change: ConfigChange::BroadcasterLogTopic {
+					domain: 2,
+					topic: Some(MESSAGE_LOG_TOPIC),
+				},
+				applicable_at: 11,
+			})
+		);
+
+		Bridge::on_initialize(11);
+		assert_eq!(
+			BroadcasterLogTopics::<Test>::get(2),
+			Some(MESSAGE_LOG_TOPIC)
+		);
+	});
+}
+
+#[test]
+fn on_initialize_applies_due_config_changes() {
+	new_test_ext().execute_with(|| {
+		announce_broadcaster_with_delay();
+
+		Bridge::on_initialize(10);
+		assert_eq!(Broadcasters::<Test>::get(2), H256::zero());
+		assert_eq!(NextConfigChangeToApply::<Test>::get(), 0);
+
+		Bridge::on_initialize(11);
+		assert_eq!(Broadcasters::<Test>::get(2), TEST_BROADCASTER);
+		assert_eq!(PendingConfigChanges::<Test>::get(0), None);
+		assert_eq!(NextConfigChangeToApply::<Test>::get(), 1);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ConfigChangeApplied { id: 0 }));
+	});
+}
+
+#[test]
+fn on_initialize_drops_config_changes_that_fail() {
+	new_test_ext().execute_with(|| {
+		ConfigChangeDelay::set(10);
+		assert_ok!(Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER));
+		assert_ok!(Bridge::add_updater(RawOrigin::Root.into(), TEST_UPDATER));
+
+		Bridge::on_initialize(11);
+		assert_eq!(Updaters::<Test>::get().to_vec(), vec![TEST_UPDATER]);
+		assert_eq!(PendingConfigChanges::<Test>::get(1), None);
+		assert_eq!(NextConfigChangeToApply::<Test>::get(), 2);
+		System::assert_has_event(RuntimeEvent::Bridge(Event::ConfigChangeApplied { id: 0 }));
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ConfigChangeFailed {
+			id: 1,
+			error: Error::<Test>::UpdaterAlreadyAuthorized.into(),
+		}));
+	});
+}
+
+#[test]
+fn apply_config_change_fails_before_delay() {
+	new_test_ext().execute_with(|| {
+		announce_broadcaster_with_delay();
+		System::set_block_number(10);
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::apply_config_change(origin, 0);
+		assert_err!(err, Error::<Test>::ConfigChangeNotApplicable);
+		assert_eq!(Broadcasters::<Test>::get(2), H256::zero());
+	});
+}
+
+#[test]
+fn apply_config_change_fails_when_not_found() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::apply_config_change(origin, 0);
+		assert_err!(err, Error::<Test>::ConfigChangeNotFound);
+	});
+}
+
+#[test]
+fn cancel_config_change_works_with_root() {
+	new_test_ext().execute_with(|| {
+		announce_broadcaster_with_delay();
+
+		let ok = Bridge::cancel_config_change(RawOrigin::Root.into(), 0);
+		assert_ok!(ok);
+		assert_eq!(PendingConfigChanges::<Test>::get(0), None);
+		System::assert_last_event(RuntimeEvent::Bridge(Event::ConfigChangeCancelled { id: 0 }));
+
+		System::set_block_number(11);
+		let err = Bridge::apply_config_change(RawOrigin::Root.into(), 0);
+		assert_err!(err, Error::<Test>::ConfigChangeNotFound);
+		assert_eq!(Broadcasters::<Test>::get(2), H256::zero());
+	});
+}
+
+#[test]
+fn cancel_config_change_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		announce_broadcaster_with_delay();
+		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
+
+		let err = Bridge::cancel_config_change(origin, 0);
+		assert_err!(err, BadOrigin);
+		assert!(PendingConfigChanges::<Test>::get(0).is_some());
+	});
+}
@@ -1,6 +1,7 @@
 use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
 use ark_groth16::{prepare_verifying_key, verify_proof, Proof, VerifyingKey};
 use ark_std::boxed::Box;
+use ark_std::format;
 use ark_std::str::FromStr;
 use ark_std::string::String;
 use ark_std::string::ToString;
@@ -10,9 +11,10 @@ use codec::{Decode, Encode};
 use ethabi::ParamType;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
+use sp1_verifier::{Groth16Verifier, PlonkVerifier, GROTH16_VK_BYTES, PLONK_VK_BYTES};
 use sp_core::{H256, U256};
 
-use crate::state::{CircomProof, PublicSignals};
+use crate::state::{CircomProof, ProofSystem, PublicSignals};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode, TypeInfo)]
 pub enum VerificationError {
@@ -271,6 +273,69 @@ pub fn encode_packed(poseidon: U256, slot: u64) -> Vec<u8> {
 	result
 }
 
+/// Verifies the SP1 proofs submitted to `fulfill` against the verification key of a program.
+pub trait ProofVerifier {
+	/// Verifies `proof` of the `public_values` of the program `program_vk`.
+	fn verify(
+		proof: &[u8],
+		public_values: &[u8],
+		program_vk: H256,
+	) -> Result<(), VerificationError>;
+}
+
+/// Verifies SP1 Groth16 proofs.
+pub struct Groth16Sp1Verifier;
+
+impl ProofVerifier for Groth16Sp1Verifier {
+	fn verify(
+		proof: &[u8],
+		public_values: &[u8],
+		program_vk: H256,
+	) -> Result<(), VerificationError> {
+		Groth16Verifier::verify(
+			proof,
+			public_values,
+			&format!("{:?}", program_vk),
+			&GROTH16_VK_BYTES,
+		)
+		.map_err(|_| VerificationError::InvalidProof)
+	}
+}
+
+/// Verifies SP1 PLONK proofs.
+pub struct PlonkSp1Verifier;
+
+impl ProofVerifier for PlonkSp1Verifier {
+	fn verify(
+		proof: &[u8],
+		public_values: &[u8],
+		program_vk: H256,
+	) -> Result<(), VerificationError> {
+		PlonkVerifier::verify(
+			proof,
+			public_values,
+			&format!("{:?}", program_vk),
+			&PLONK_VK_BYTES,
+		)
+		.map_err(|_| VerificationError::InvalidProof)
+	}
+}
+
+impl ProofSystem {
+	/// Verifies `proof` with the verifier of this proof system.
+	pub fn verify(
+		self,
+		proof: &[u8],
+		public_values: &[u8],
+		program_vk: H256,
+	) -> Result<(), VerificationError> {
+		match self {
+			ProofSystem::Groth16Sp1 => Groth16Sp1Verifier::verify(proof, public_values, program_vk),
+			ProofSystem::PlonkSp1 => PlonkSp1Verifier::verify(proof, public_values, program_vk),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use frame_support::assert_ok;
@@ -0,0 +1,133 @@
+use da_runtime::{apis::DataAvailApi, AppId, Balance};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::ErrorObject,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, AccountId32};
+use std::{marker::PhantomData, sync::Arc};
+
+pub type HashOf<Block> = <Block as BlockT>::Hash;
+
+#[rpc(client, server)]
+pub trait AppKeysApi<Block>
+where
+	Block: BlockT,
+{
+	#[method(name = "da_appKeyById")]
+	async fn app_key_by_id(
+		&self,
+		app_id: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Option<AppKeyResponse>>;
+
+	#[method(name = "da_appKeysByOwner")]
+	async fn app_keys_by_owner(
+		&self,
+		owner: AccountId32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<AppKeyResponse>>;
+}
+
+/// An application key as returned by the `da_appKey*` RPCs.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppKeyResponse {
+	/// Hex encoded application key.
+	pub key: String,
+	pub app_id: u32,
+	pub owner: AccountId32,
+	pub deposit: Balance,
+}
+
+pub struct AppKeys<Client, Block: BlockT> {
+	client: Arc<Client>,
+	_block: PhantomData<Block>,
+}
+
+impl<Client, Block: BlockT> AppKeys<Client, Block> {
+	pub fn new(client: Arc<Client>) -> Self {
+		Self {
+			client,
+			_block: PhantomData,
+		}
+	}
+}
+
+/// Error type for this RPC API.
+pub enum Error {
+	/// Generic runtime error.
+	RuntimeApi,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeApi => 1,
+		}
+	}
+}
+
+macro_rules! internal_err {
+	($($arg:tt)*) => {{
+		ErrorObject::owned(
+			Error::RuntimeApi.into(),
+			format!($($arg)*),
+			None::<()>
+		)
+	}}
+}
+
+#[async_trait]
+impl<Client, Block> AppKeysApiServer<Block> for AppKeys<Client, Block>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block> + ProvideRuntimeApi<Block>,
+	Client::Api: DataAvailApi<Block>,
+{
+	async fn app_key_by_id(
+		&self,
+		app_id: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Option<AppKeyResponse>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let app_key = self
+			.client
+			.runtime_api()
+			.app_key_by_id(at, AppId(app_id))
+			.map_err(|e| internal_err!("Application key {app_id} at {at:?}: {e:?}"))?;
+
+		Ok(app_key.map(|(key, info)| AppKeyResponse {
+			key: const_hex::encode_prefixed(&key),
+			app_id: info.id.0,
+			owner: info.owner,
+			deposit: info.deposit,
+		}))
+	}
+
+	async fn app_keys_by_owner(
+		&self,
+		owner: AccountId32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<AppKeyResponse>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let app_keys = self
+			.client
+			.runtime_api()
+			.app_keys_by_owner(at, owner.clone())
+			.map_err(|e| internal_err!("Application keys of {owner} at {at:?}: {e:?}"))?;
+
+		Ok(app_keys
+			.into_iter()
+			.map(|(key, info)| AppKeyResponse {
+				key: const_hex::encode_prefixed(&key),
+				app_id: info.id.0,
+				owner: info.owner,
+				deposit: info.deposit,
+			})
+			.collect())
+	}
+}
@@ -3,7 +3,7 @@ use avail_core::{
 	data_proof::ProofResponse, header::HeaderExtension, traits::ExtendedHeader, OpaqueExtrinsic,
 };
 use da_runtime::apis::{DataAvailApi, KateApi as RTKateApi};
-use da_runtime::kate::{GCellBlock, GDataProof, GMultiProof, GRow};
+use da_runtime::kate::{GAppRow, GCellBlock, GDataProof, GMultiProof, GRow};
 use kate::com::Cell;
 
 use frame_support::BoundedVec;
@@ -14,7 +14,7 @@ use jsonrpsee::{
 	types::error::{ErrorCode, ErrorObject},
 };
 use sc_client_api::BlockBackend;
-use sp_api::{ApiRef, ProvideRuntimeApi};
+use sp_api::{ApiExt, ApiRef, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{
 	generic::SignedBlock,
@@ -28,9 +28,15 @@ pub type Rows = BoundedVec<u32, MaxRows>;
 pub type MaxCells = ConstU32<10_000>;
 pub type Cells = BoundedVec<Cell, MaxCells>;
 
+/// Column extension factor used to build the commitments: the original row `y` is located at
+/// row `y * EXTENSION_FACTOR` of the extended grid.
+const EXTENSION_FACTOR: u32 = 2;
+
+pub mod app_keys;
 pub mod justifications;
 pub mod metrics;
 pub mod system;
+pub mod vector;
 
 #[derive(Clone, Default)]
 pub struct Deps {
@@ -77,6 +83,23 @@ where
 		transaction_index: u32,
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<ProofResponse>;
+
+	#[method(name = "kate_queryAppData")]
+	async fn query_app_data(
+		&self,
+		app_id: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<GAppRow>>;
+
+	/// Same as `kate_queryDataProof`, but for the blob `blob_index` of a transaction submitting
+	/// several blobs (i.e. `submit_data_batch`).
+	#[method(name = "kate_queryBlobDataProof")]
+	async fn query_blob_data_proof(
+		&self,
+		transaction_index: u32,
+		blob_index: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<ProofResponse>;
 }
 
 #[allow(clippy::type_complexity)]
@@ -329,4 +352,109 @@ where
 
 		Ok(proof)
 	}
+
+	async fn query_blob_data_proof(
+		&self,
+		tx_idx: u32,
+		blob_idx: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<ProofResponse> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryDataProof);
+
+		let (api, at, number, _, extrinsics, _) = self.scope(at)?;
+		let supported = api
+			.has_api_with::<dyn RTKateApi<Block>, _>(at, |version| version >= 3)
+			.map_err(|e| internal_err!("KateApi version at {at:?}: {e:?}"))?;
+		if !supported {
+			return Err(internal_err!(
+				"The runtime of block {at:?} does not support KateApi::blob_data_proof"
+			));
+		}
+
+		let proof = api
+			.blob_data_proof(at, number, extrinsics, tx_idx, blob_idx)
+			.map_err(|e| internal_err!("KateApi::blob_data_proof failed: {e:?}"))?
+			.ok_or_else(|| {
+				internal_err!(
+					"Cannot fetch blob {blob_idx:?} at tx index {tx_idx:?} at block {at:?}"
+				)
+			})?;
+
+		Ok(proof)
+	}
+
+	async fn query_app_data(
+		&self,
+		app_id: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<GAppRow>> {
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryAppData);
+
+		let (api, at, number, block_len, extrinsics, header) = self.scope(at)?;
+		match header.extension() {
+			HeaderExtension::V3(ext) => {
+				// A block without commitments has no data, for any application.
+				if ext.commitment.commitment.is_empty() {
+					return Ok(Vec::new());
+				}
+			},
+		};
+
+		let supported = api
+			.has_api_with::<dyn RTKateApi<Block>, _>(at, |version| version >= 2)
+			.map_err(|e| internal_err!("KateApi version at {at:?}: {e:?}"))?;
+		if !supported {
+			return Err(internal_err!(
+				"The runtime of block {at:?} does not support KateApi::app_data"
+			));
+		}
+
+		let app_rows = api
+			.app_data(at, number, extrinsics.clone(), block_len.clone(), app_id)
+			.map_err(|kate_err| internal_err!("KateApi::app_data failed: {kate_err:?}"))?
+			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?
+			.into_iter()
+			.enumerate()
+			.filter_map(|(row, data)| Some((u32::try_from(row).ok()?, data?)))
+			.collect::<Vec<_>>();
+		if app_rows.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let cells_len = app_rows.iter().map(|(_, data)| data.len()).sum::<usize>();
+		if cells_len > self.max_cells_size {
+			return Err(
+				internal_err!(
+					"Cannot prove ({}) more than {} amount of cells per request. Either increase the max cells size (--kate-max-cells-size) or query a smaller block.",
+					cells_len,
+					self.max_cells_size
+				)
+			);
+		}
+
+		// Proofs are generated over the extended grid.
+		let cells = app_rows
+			.iter()
+			.flat_map(|(row, data)| {
+				let ext_row = row.saturating_mul(EXTENSION_FACTOR);
+				(0..data.len() as u32).map(move |col| (ext_row, col))
+			})
+			.collect::<Vec<_>>();
+		let mut proofs = api
+			.proof(at, number, extrinsics, block_len, cells)
+			.map_err(|kate_err| internal_err!("KateApi::proof failed: {kate_err:?}"))?
+			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?
+			.into_iter()
+			.map(|(_, proof)| proof);
+
+		let app_rows = app_rows
+			.into_iter()
+			.map(|(row, data)| {
+				let proofs = proofs.by_ref().take(data.len()).collect();
+				GAppRow { row, data, proofs }
+			})
+			.collect();
+
+		Ok(app_rows)
+	}
 }
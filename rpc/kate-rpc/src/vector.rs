@@ -0,0 +1,327 @@
+use crate::{Kate, KateApiServer};
+use avail_core::{
+	data_proof::{MessageType, ProofResponse},
+	header::HeaderExtension,
+	traits::ExtendedHeader,
+	OpaqueExtrinsic,
+};
+use da_runtime::{
+	apis::{DataAvailApi, KateApi as RTKateApi, VectorApi as RTVectorApi},
+	AccountId,
+};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::ErrorObject,
+};
+use pallet_vector::{
+	split_message_id, Configuration, MessageStatusEnum, OutboundMessage, OutboundMessageStatus,
+};
+use sc_client_api::BlockBackend;
+use sp_api::{ApiExt, ApiRef, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::sync::Arc;
+
+pub type HashOf<Block> = <Block as BlockT>::Hash;
+
+/// The maximum number of outbound messages that can be requested in one go.
+const MAX_OUTBOUND_MESSAGES: u32 = 1_000;
+
+#[rpc(client, server)]
+pub trait VectorApi<Block>
+where
+	Block: BlockT,
+{
+	#[method(name = "vector_outboundMessages")]
+	async fn outbound_messages(
+		&self,
+		start_after: Option<u64>,
+		limit: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<OutboundMessageResponse>>;
+
+	#[method(name = "vector_outboundMessageProof")]
+	async fn outbound_message_proof(&self, message_id: u64) -> RpcResult<OutboundMessageProof>;
+
+	#[method(name = "vector_executionStateRoot")]
+	async fn execution_state_root(&self, slot: u64, at: Option<HashOf<Block>>) -> RpcResult<H256>;
+
+	#[method(name = "vector_timestamp")]
+	async fn timestamp(&self, slot: u64, at: Option<HashOf<Block>>) -> RpcResult<u64>;
+
+	#[method(name = "vector_syncCommitteeHash")]
+	async fn sync_committee_hash(&self, period: u64, at: Option<HashOf<Block>>) -> RpcResult<H256>;
+
+	#[method(name = "vector_configuration")]
+	async fn configuration(&self, at: Option<HashOf<Block>>) -> RpcResult<Configuration>;
+
+	#[method(name = "vector_broadcaster")]
+	async fn broadcaster(&self, domain: u32, at: Option<HashOf<Block>>) -> RpcResult<H256>;
+
+	#[method(name = "vector_isFrozen")]
+	async fn is_frozen(&self, domain: u32, at: Option<HashOf<Block>>) -> RpcResult<bool>;
+
+	#[method(name = "vector_whitelistedDomains")]
+	async fn whitelisted_domains(&self, at: Option<HashOf<Block>>) -> RpcResult<Vec<u32>>;
+
+	#[method(name = "vector_messageStatus")]
+	async fn message_status(
+		&self,
+		message_root: H256,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<MessageStatusEnum>;
+
+	#[method(name = "vector_updater")]
+	async fn updater(&self, at: Option<HashOf<Block>>) -> RpcResult<H256>;
+}
+
+/// A message sent from Avail as returned by the `vector_outboundMessage*` RPCs.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboundMessageResponse {
+	/// The `tx_uid` of the `send_message` transaction.
+	pub message_id: u64,
+	pub from: AccountId,
+	pub to: H256,
+	pub destination_domain: u32,
+	pub message_type: MessageType,
+	pub block_number: u32,
+	pub status: OutboundMessageStatus,
+}
+
+impl OutboundMessageResponse {
+	fn new(message_id: u64, message: OutboundMessage<AccountId>) -> Self {
+		Self {
+			message_id,
+			from: message.from,
+			to: message.to,
+			destination_domain: message.destination_domain,
+			message_type: message.message_type,
+			block_number: message.block_number,
+			status: message.status,
+		}
+	}
+}
+
+/// A message sent from Avail with the data proof of its transaction, as `kate_queryDataProof`
+/// returns it.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboundMessageProof {
+	pub message: OutboundMessageResponse,
+	pub data_proof: ProofResponse,
+}
+
+pub struct Vector<Client, Block: BlockT> {
+	client: Arc<Client>,
+	kate: Kate<Client, Block>,
+}
+
+impl<Client, Block: BlockT> Vector<Client, Block> {
+	pub fn new(client: Arc<Client>, max_cells_size: usize) -> Self {
+		Self {
+			kate: Kate::new(client.clone(), max_cells_size),
+			client,
+		}
+	}
+}
+
+impl<Client, Block> Vector<Client, Block>
+where
+	Block: BlockT,
+	Client: HeaderBackend<Block>,
+{
+	fn at_or_best(&self, at: Option<HashOf<Block>>) -> HashOf<Block> {
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+/// Error type for this RPC API.
+pub enum Error {
+	/// Generic runtime error.
+	RuntimeApi,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeApi => 1,
+		}
+	}
+}
+
+macro_rules! internal_err {
+	($($arg:tt)*) => {{
+		ErrorObject::owned(
+			Error::RuntimeApi.into(),
+			format!($($arg)*),
+			None::<()>
+		)
+	}}
+}
+
+impl<Client, Block> Vector<Client, Block>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block>,
+	Client::Api: RTVectorApi<Block>,
+{
+	/// The runtime API of block `at`, if its `VectorApi` has `method`, added in `version`.
+	fn vector_api(
+		&self,
+		at: HashOf<Block>,
+		version: u32,
+		method: &str,
+	) -> RpcResult<ApiRef<'_, Client::Api>> {
+		let api = self.client.runtime_api();
+		let supported = api
+			.has_api_with::<dyn RTVectorApi<Block>, _>(at, |v| v >= version)
+			.map_err(|e| internal_err!("VectorApi version at {at:?}: {e:?}"))?;
+		if !supported {
+			return Err(internal_err!(
+				"The runtime of block {at:?} does not support VectorApi::{method}"
+			));
+		}
+
+		Ok(api)
+	}
+}
+
+#[async_trait]
+impl<Client, Block> VectorApiServer<Block> for Vector<Client, Block>
+where
+	Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+	<Block as BlockT>::Header: ExtendedHeader<Extension = HeaderExtension>,
+	<<Block as BlockT>::Header as HeaderT>::Number: From<u32>,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block> + ProvideRuntimeApi<Block> + BlockBackend<Block>,
+	Client::Api: DataAvailApi<Block> + RTKateApi<Block> + RTVectorApi<Block>,
+{
+	async fn outbound_messages(
+		&self,
+		start_after: Option<u64>,
+		limit: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<Vec<OutboundMessageResponse>> {
+		if limit > MAX_OUTBOUND_MESSAGES {
+			return Err(internal_err!(
+				"Cannot query more than {MAX_OUTBOUND_MESSAGES} outbound messages per request"
+			));
+		}
+
+		let at = self.at_or_best(at);
+		let messages = self
+			.vector_api(at, 3, "outbound_messages")?
+			.outbound_messages(at, start_after, limit)
+			.map_err(|e| internal_err!("Outbound messages at {at:?}: {e:?}"))?;
+
+		Ok(messages
+			.into_iter()
+			.map(|(message_id, message)| OutboundMessageResponse::new(message_id, message))
+			.collect())
+	}
+
+	async fn outbound_message_proof(&self, message_id: u64) -> RpcResult<OutboundMessageProof> {
+		// Only messages of finalized blocks are bridged, their proofs are served at finality.
+		let info = self.client.info();
+		let (number, tx_index) = split_message_id(message_id);
+		if info.finalized_number < number.into() {
+			return Err(internal_err!(
+				"Outbound message {message_id} is not finalized yet"
+			));
+		}
+
+		let finalized = info.finalized_hash;
+		let message = self
+			.vector_api(finalized, 3, "outbound_message")?
+			.outbound_message(finalized, message_id)
+			.map_err(|e| internal_err!("Outbound message {message_id} at {finalized:?}: {e:?}"))?
+			.ok_or_else(|| internal_err!("Outbound message {message_id} is not logged"))?;
+		if message.status != OutboundMessageStatus::Sent {
+			return Err(internal_err!(
+				"Outbound message {message_id} failed and is not bridged"
+			));
+		}
+
+		let at = self
+			.client
+			.hash(number.into())
+			.map_err(|e| internal_err!("Hash of block {number}: {e:?}"))?
+			.ok_or_else(|| internal_err!("Missing block {number}"))?;
+		let data_proof = self.kate.query_data_proof(tx_index, Some(at)).await?;
+
+		Ok(OutboundMessageProof {
+			message: OutboundMessageResponse::new(message_id, message),
+			data_proof,
+		})
+	}
+
+	async fn execution_state_root(&self, slot: u64, at: Option<HashOf<Block>>) -> RpcResult<H256> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "execution_state_root")?
+			.execution_state_root(at, slot)
+			.map_err(|e| internal_err!("Execution state root of slot {slot} at {at:?}: {e:?}"))
+	}
+
+	async fn timestamp(&self, slot: u64, at: Option<HashOf<Block>>) -> RpcResult<u64> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "timestamp")?
+			.timestamp(at, slot)
+			.map_err(|e| internal_err!("Timestamp of slot {slot} at {at:?}: {e:?}"))
+	}
+
+	async fn sync_committee_hash(&self, period: u64, at: Option<HashOf<Block>>) -> RpcResult<H256> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "sync_committee_hash")?
+			.sync_committee_hash(at, period)
+			.map_err(|e| internal_err!("Sync committee hash of period {period} at {at:?}: {e:?}"))
+	}
+
+	async fn configuration(&self, at: Option<HashOf<Block>>) -> RpcResult<Configuration> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "configuration")?
+			.configuration(at)
+			.map_err(|e| internal_err!("Configuration at {at:?}: {e:?}"))
+	}
+
+	async fn broadcaster(&self, domain: u32, at: Option<HashOf<Block>>) -> RpcResult<H256> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "broadcaster")?
+			.broadcaster(at, domain)
+			.map_err(|e| internal_err!("Broadcaster of domain {domain} at {at:?}: {e:?}"))
+	}
+
+	async fn is_frozen(&self, domain: u32, at: Option<HashOf<Block>>) -> RpcResult<bool> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "is_frozen")?
+			.is_frozen(at, domain)
+			.map_err(|e| internal_err!("Frozen status of domain {domain} at {at:?}: {e:?}"))
+	}
+
+	async fn whitelisted_domains(&self, at: Option<HashOf<Block>>) -> RpcResult<Vec<u32>> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "whitelisted_domains")?
+			.whitelisted_domains(at)
+			.map_err(|e| internal_err!("Whitelisted domains at {at:?}: {e:?}"))
+	}
+
+	async fn message_status(
+		&self,
+		message_root: H256,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<MessageStatusEnum> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "message_status")?
+			.message_status(at, message_root)
+			.map_err(|e| internal_err!("Status of message {message_root:?} at {at:?}: {e:?}"))
+	}
+
+	async fn updater(&self, at: Option<HashOf<Block>>) -> RpcResult<H256> {
+		let at = self.at_or_best(at);
+		self.vector_api(at, 4, "updater")?
+			.updater(at)
+			.map_err(|e| internal_err!("Updater at {at:?}: {e:?}"))
+	}
+}
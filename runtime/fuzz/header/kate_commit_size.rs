@@ -11,7 +11,9 @@ use std::{
 };
 
 use avail_core::header::HeaderExtension;
-use da_control::{pallet::Call as DaControlCall, AppDataFor, CheckAppId};
+use da_control::{
+	pallet::Call as DaControlCall, AppDataFor, ChargeAppTransactionPayment, CheckAppId,
+};
 use da_runtime::{
 	AppId, Executive, Header, Runtime, RuntimeCall, RuntimeGenesisConfig, SignedExtra,
 	SignedPayload, Timestamp, UncheckedExtrinsic, AVAIL,
@@ -80,8 +82,10 @@ fn submit_data(
 		CheckEra::<Runtime>::from(Era::Immortal),
 		CheckNonce::<Runtime>::from(nonce),
 		CheckWeight::<Runtime>::new(),
-		ChargeTransactionPayment::<Runtime>::from(0),
-		CheckAppId::<Runtime>::from(app_id),
+		ChargeAppTransactionPayment::<Runtime>::new(
+			ChargeTransactionPayment::from(0),
+			CheckAppId::from(app_id),
+		),
 	);
 	let payload =
 		SignedPayload::new(call, extra).map_err(|e| anyhow!("Failed to create payload: {e:?}"))?;
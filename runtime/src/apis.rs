@@ -13,17 +13,24 @@ use avail_core::{
 	currency::Balance,
 	data_proof::{DataProof, ProofResponse, SubTrie},
 	header::HeaderExtension,
-	OpaqueExtrinsic,
+	traits::GetAppId,
+	AppId, OpaqueExtrinsic,
+};
+use da_control::{
+	weight_helper::submit_data_fee_modifier, AppKeyFor, AppKeyInfoFor, AppMetadataFor,
+	Call as DACall,
 };
 
 use frame_system::limits::BlockLength;
 
 use frame_support::{
+	dispatch::{DispatchInfo, GetDispatchInfo},
 	genesis_builder_helper::{build_config, create_default_config},
 	traits::KeyOwnerProofSystem,
 	weights::Weight,
 };
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
+use pallet_vector::{Configuration, MessageStatusEnum, OutboundMessage};
 use sp_api::{decl_runtime_apis, impl_runtime_apis};
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -41,9 +48,21 @@ type RTExtractor = <Runtime as frame_system::Config>::HeaderExtensionDataFilter;
 type RTExtrinsic = <Runtime as frame_system::Config>::Extrinsic;
 
 decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(5)]
 	pub trait DataAvailApi {
 		fn block_length() -> BlockLength;
+		/// The scheduled block length proposal and the block where it will be enacted, if any.
+		#[api_version(3)]
+		fn pending_block_length() -> Option<(BlockNumber, BlockLength)>;
+		/// Up to `limit` application keys with their info and metadata, starting after `start_after`.
+		#[api_version(4)]
+		fn apps(start_after: Option<AppKeyFor<Runtime>>, limit: u32) -> Vec<(AppKeyFor<Runtime>, AppKeyInfoFor<Runtime>, Option<AppMetadataFor<Runtime>>)>;
+		/// The application key registered under `id` and its info, if any.
+		#[api_version(5)]
+		fn app_key_by_id(id: AppId) -> Option<(AppKeyFor<Runtime>, AppKeyInfoFor<Runtime>)>;
+		/// The application keys owned by `owner` and their info.
+		#[api_version(5)]
+		fn app_keys_by_owner(owner: AccountId) -> Vec<(AppKeyFor<Runtime>, AppKeyInfoFor<Runtime>)>;
 	}
 
 	pub trait ExtensionBuilder {
@@ -59,17 +78,59 @@ decl_runtime_apis! {
 		fn check_if_extrinsic_is_post_inherent(uxt: &<Block as BlockT>::Extrinsic) -> bool;
 	}
 
+	#[api_version(4)]
 	pub trait VectorApi {
 		fn sync_committee_poseidons(slot: u64) -> U256;
 		fn head() -> u64;
 		fn headers(slot: u64) -> H256;
+		/// The transaction indices of the `send_message` calls that failed in the block.
+		#[api_version(2)]
+		fn failed_send_message_txs() -> Vec<u32>;
+		/// Up to `limit` logged outbound messages with their ids, starting after the message `start_after`.
+		#[api_version(3)]
+		fn outbound_messages(start_after: Option<u64>, limit: u32) -> Vec<(u64, OutboundMessage<AccountId>)>;
+		/// The logged outbound message with id `message_id`, if any.
+		#[api_version(3)]
+		fn outbound_message(message_id: u64) -> Option<OutboundMessage<AccountId>>;
+		/// The execution state root of `slot`, zero if it is not stored.
+		#[api_version(4)]
+		fn execution_state_root(slot: u64) -> H256;
+		/// The timestamp of `slot`, zero if it is not stored.
+		#[api_version(4)]
+		fn timestamp(slot: u64) -> u64;
+		/// The sync committee hash of `period`, zero if it is not stored.
+		#[api_version(4)]
+		fn sync_committee_hash(period: u64) -> H256;
+		#[api_version(4)]
+		fn configuration() -> Configuration;
+		/// The broadcaster of `domain`, zero if it is not set.
+		#[api_version(4)]
+		fn broadcaster(domain: u32) -> H256;
+		/// Whether the messages from `domain` are frozen.
+		#[api_version(4)]
+		fn is_frozen(domain: u32) -> bool;
+		#[api_version(4)]
+		fn whitelisted_domains() -> Vec<u32>;
+		/// The execution status of the message with root `message_root`.
+		#[api_version(4)]
+		fn message_status(message_root: H256) -> MessageStatusEnum;
+		#[api_version(4)]
+		fn updater() -> H256;
 	}
 
+	#[api_version(3)]
 	pub trait KateApi {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse>;
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError >;
 		fn proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)> ) -> Result<Vec<GDataProof>, RTKateError>;
 		fn multiproof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)> ) -> Result<Vec<(GMultiProof, GCellBlock)>, RTKateError>;
+		/// The rows of the original, non-extended, grid that contain data of `app_id`, `None` for the
+		/// other rows.
+		#[api_version(2)]
+		fn app_data(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, app_id: u32) -> Result<Vec<Option<GRow>>, RTKateError>;
+		/// Like `data_proof`, but for the blob `blob_idx` of a transaction submitting several blobs.
+		#[api_version(3)]
+		fn blob_data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32, blob_idx: u32) -> Option<ProofResponse>;
 	}
 }
 
@@ -287,10 +348,22 @@ impl_runtime_apis! {
 		Balance,
 	> for Runtime {
 		fn query_info(uxt: <Block as BlockT>::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance> {
-			TransactionPayment::query_info(uxt, len)
+			let info = app_dispatch_info(&uxt);
+			let partial_fee = if uxt.is_signed().unwrap_or(false) {
+				TransactionPayment::compute_fee(len, &info, 0)
+			} else {
+				0
+			};
+
+			RuntimeDispatchInfo { weight: info.weight, class: info.class, partial_fee }
 		}
 		fn query_fee_details(uxt: <Block as BlockT>::Extrinsic, len: u32) -> FeeDetails<Balance> {
-			TransactionPayment::query_fee_details(uxt, len)
+			let info = app_dispatch_info(&uxt);
+			if uxt.is_signed().unwrap_or(false) {
+				TransactionPayment::compute_fee_details(len, &info, 0)
+			} else {
+				FeeDetails { inclusion_fee: None, tip: 0 }
+			}
 		}
 
 		fn query_weight_to_fee(weight: Weight) -> Balance {
@@ -393,6 +466,22 @@ impl_runtime_apis! {
 		fn block_length() -> frame_system::limits::BlockLength {
 			frame_system::Pallet::<Runtime>::block_length()
 		}
+
+		fn pending_block_length() -> Option<(BlockNumber, frame_system::limits::BlockLength)> {
+			da_control::Pallet::<Runtime>::pending_block_length()
+		}
+
+		fn apps(start_after: Option<AppKeyFor<Runtime>>, limit: u32) -> Vec<(AppKeyFor<Runtime>, AppKeyInfoFor<Runtime>, Option<AppMetadataFor<Runtime>>)> {
+			da_control::Pallet::<Runtime>::apps(start_after, limit)
+		}
+
+		fn app_key_by_id(id: AppId) -> Option<(AppKeyFor<Runtime>, AppKeyInfoFor<Runtime>)> {
+			da_control::Pallet::<Runtime>::app_key_by_id(id)
+		}
+
+		fn app_keys_by_owner(owner: AccountId) -> Vec<(AppKeyFor<Runtime>, AppKeyInfoFor<Runtime>)> {
+			da_control::Pallet::<Runtime>::app_keys_by_owner(&owner)
+		}
 	}
 
 	#[api_version(4)]
@@ -441,36 +530,63 @@ impl_runtime_apis! {
 		fn headers(slot: u64) -> H256 {
 			pallet_vector::Pallet::<Runtime>::headers(slot)
 		}
+
+		fn failed_send_message_txs() -> Vec<u32> {
+			pallet_vector::FailedSendMessageTxs::<Runtime>::get()
+		}
+
+		fn outbound_messages(start_after: Option<u64>, limit: u32) -> Vec<(u64, OutboundMessage<AccountId>)> {
+			pallet_vector::Pallet::<Runtime>::outbound_messages(start_after, limit)
+		}
+
+		fn outbound_message(message_id: u64) -> Option<OutboundMessage<AccountId>> {
+			pallet_vector::Pallet::<Runtime>::outbound_message(message_id)
+		}
+
+		fn execution_state_root(slot: u64) -> H256 {
+			pallet_vector::ExecutionStateRoots::<Runtime>::get(slot)
+		}
+
+		fn timestamp(slot: u64) -> u64 {
+			pallet_vector::Timestamps::<Runtime>::get(slot)
+		}
+
+		fn sync_committee_hash(period: u64) -> H256 {
+			pallet_vector::SyncCommitteeHashes::<Runtime>::get(period)
+		}
+
+		fn configuration() -> Configuration {
+			pallet_vector::ConfigurationStorage::<Runtime>::get()
+		}
+
+		fn broadcaster(domain: u32) -> H256 {
+			pallet_vector::Broadcasters::<Runtime>::get(domain)
+		}
+
+		fn is_frozen(domain: u32) -> bool {
+			pallet_vector::SourceChainFrozen::<Runtime>::get(domain)
+		}
+
+		fn whitelisted_domains() -> Vec<u32> {
+			pallet_vector::WhitelistedDomains::<Runtime>::get().into_inner()
+		}
+
+		fn message_status(message_root: H256) -> MessageStatusEnum {
+			pallet_vector::MessageStatus::<Runtime>::get(message_root)
+		}
+
+		fn updater() -> H256 {
+			pallet_vector::Updater::<Runtime>::get()
+		}
 	}
 
 	impl crate::apis::KateApi<Block> for Runtime {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse> {
-			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
-			let (leaf_idx, sub_trie) = data.leaf_idx(tx_idx)?;
-			log::trace!(
-				target: LOG_TARGET,
-				"KateApi::data_proof: tx_idx={tx_idx:?} leaf_idx={leaf_idx:?}, sub_trie:{sub_trie:?}");
-
-			let (sub_proof, message) = match sub_trie {
-				SubTrie::DataSubmit => {
-					let proof = data.submitted_proof_of(leaf_idx)?;
-					(proof, None)
-				},
-				SubTrie::Bridge => {
-					let message = data.bridge_messages.get(leaf_idx).map(|b| b.addr_msg.clone());
-					let proof = data.bridged_proof_of(leaf_idx)?;
-					(proof, message)
-				},
-			};
-
-			let roots = data.roots();
-			let data_proof = DataProof::new(sub_trie, roots, sub_proof);
-			let proof = ProofResponse::new(data_proof, message);
-			log::trace!(
-				target: LOG_TARGET,
-				"KateApi::data_proof: proof={proof:#?}");
+			blob_data_proof(block_number, &extrinsics, tx_idx, 0)
+		}
 
-			Some(proof)
+		fn blob_data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32, blob_idx: u32) -> Option<ProofResponse> {
+			blob_data_proof(block_number, &extrinsics, tx_idx, blob_idx)
 		}
 
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError> {
@@ -493,6 +609,13 @@ impl_runtime_apis! {
 			log::trace!(target: LOG_TARGET, "KateApi::proof: data_proofs={data_proofs:#?}");
 			Ok(data_proofs)
 		}
+
+		fn app_data(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, app_id: u32) -> Result<Vec<Option<GRow>>, RTKateError> {
+			let app_extrinsics = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics).to_app_extrinsics();
+			let app_rows = super::kate::app_data::<Runtime>(app_extrinsics, block_len, app_id)?;
+			log::trace!(target: LOG_TARGET, "KateApi::app_data: app_id={app_id} app_rows={app_rows:#?}");
+			Ok(app_rows)
+		}
 	}
 
 	impl avail_base::PostInherentsProvider<Block> for Runtime {
@@ -622,6 +745,54 @@ impl_runtime_apis! {
 	}
 }
 
+/// The dispatch info of `uxt`, with the fee modifier `ChargeAppTransactionPayment` charges it
+/// with: `submit_data` uses the fee modifier of its `AppId`.
+fn app_dispatch_info(uxt: &<Block as BlockT>::Extrinsic) -> DispatchInfo {
+	let mut info = uxt.get_dispatch_info();
+	if let RuntimeCall::DataAvailability(DACall::submit_data { .. }) = &uxt.function {
+		info.fee_modifier = submit_data_fee_modifier::<Runtime>(uxt.app_id());
+	}
+
+	info
+}
+
+/// The data proof of the blob `blob_idx` of the transaction `tx_idx`.
+fn blob_data_proof(
+	block_number: u32,
+	extrinsics: &[OpaqueExtrinsic],
+	tx_idx: u32,
+	blob_idx: u32,
+) -> Option<ProofResponse> {
+	let data =
+		HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, extrinsics);
+	let (leaf_idx, sub_trie) = data.blob_leaf_idx(tx_idx, blob_idx)?;
+	log::trace!(
+		target: LOG_TARGET,
+		"KateApi::data_proof: tx_idx={tx_idx:?} blob_idx={blob_idx:?} leaf_idx={leaf_idx:?}, sub_trie:{sub_trie:?}");
+
+	let (sub_proof, message) = match sub_trie {
+		SubTrie::DataSubmit => {
+			let proof = data.submitted_proof_of(leaf_idx)?;
+			(proof, None)
+		},
+		SubTrie::Bridge => {
+			let message = data
+				.bridge_messages
+				.get(leaf_idx)
+				.map(|b| b.addr_msg.clone());
+			let proof = data.bridged_proof_of(leaf_idx)?;
+			(proof, message)
+		},
+	};
+
+	let roots = data.roots();
+	let data_proof = DataProof::new(sub_trie, roots, sub_proof);
+	let proof = ProofResponse::new(data_proof, message);
+	log::trace!(target: LOG_TARGET, "KateApi::data_proof: proof={proof:#?}");
+
+	Some(proof)
+}
+
 fn decode_runtime_event_v1(event: &super::RuntimeEvent) -> Option<Vec<u8>> {
 	use super::*;
 	use codec::Encode;
@@ -358,8 +358,13 @@ pub mod da {
 		pub const MaxBlockRows: BlockLengthRows = BlockLengthRows(1024);
 		pub const MinBlockCols: BlockLengthColumns = BlockLengthColumns(64);
 		pub const MaxBlockCols: BlockLengthColumns = BlockLengthColumns(1024);
+		pub const AppKeyDeposit: Balance = 10 * AVAIL;
+		pub const GridUtilizationWindow: u32 = 10;
+		pub const AppMetadataDepositPerByte: Balance = AVAIL / 100;
+		pub const MaxSponsoredFee: Balance = 10 * AVAIL;
 	}
 	pub type MaxAppKeyLength = ConstU32<64>;
+	pub type MaxAppMetadataLength = ConstU32<128>;
 	pub type MaxAppDataLength = ConstU32<1_048_576>; // 1 Mb
 }
 
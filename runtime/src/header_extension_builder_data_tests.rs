@@ -3,7 +3,7 @@ use crate::{Runtime, SignedExtra, UncheckedExtrinsic};
 
 use avail_base::HeaderExtensionBuilderData;
 use avail_core::data_proof::{BoundedData, Message, TxDataRoots};
-use da_control::{AppDataFor, Call as DaCall, CheckAppId};
+use da_control::{AppDataFor, Call as DaCall, ChargeAppTransactionPayment, CheckAppId};
 use frame_system::{
 	CheckEra, CheckGenesis, CheckNonZeroSender, CheckNonce, CheckSpecVersion, CheckTxVersion,
 	CheckWeight,
@@ -111,8 +111,10 @@ fn extra() -> SignedExtra {
 		CheckEra::<Runtime>::from(Era::Mortal(32, 2)),
 		CheckNonce::<Runtime>::from(0),
 		CheckWeight::<Runtime>::new(),
-		ChargeTransactionPayment::<Runtime>::from(0),
-		CheckAppId::<Runtime>::from(AppId(1)),
+		ChargeAppTransactionPayment::<Runtime>::new(
+			ChargeTransactionPayment::from(0),
+			CheckAppId::from(AppId(1)),
+		),
 	)
 }
 fn additional_signed() -> <SignedExtra as SignedExtension>::AdditionalSigned {
@@ -121,7 +123,7 @@ fn additional_signed() -> <SignedExtra as SignedExtension>::AdditionalSigned {
 	let genesis = H256::default();
 	let era = H256::repeat_byte(1);
 
-	((), spec_ver, tx_ver, genesis, era, (), (), (), ())
+	((), spec_ver, tx_ver, genesis, era, (), (), ())
 }
 
 fn signed_extrinsic(function: RuntimeCall) -> Vec<u8> {
@@ -143,6 +145,23 @@ fn submit_data(data: Vec<u8>) -> Vec<u8> {
 	signed_extrinsic(function)
 }
 
+fn unsigned_submit_data(data: Vec<u8>) -> Vec<u8> {
+	let data = AppDataFor::<Runtime>::truncate_from(data);
+	let function: RuntimeCall = DaCall::submit_data { data }.into();
+
+	UncheckedExtrinsic::new_unsigned(function).encode()
+}
+
+fn submit_data_batch(blobs: Vec<(u32, Vec<u8>)>) -> Vec<u8> {
+	let blobs = blobs
+		.into_iter()
+		.map(|(id, data)| (AppId(id), AppDataFor::<Runtime>::truncate_from(data)))
+		.collect();
+	let function = DaCall::submit_data_batch { blobs }.into();
+
+	signed_extrinsic(function)
+}
+
 fn transfer_keep_alive() -> Vec<u8> {
 	let bob = Bob.to_account_id();
 	let amount = 1 * AVAIL;
@@ -293,6 +312,55 @@ mod to_app_extrinsics_tests {
 
 		assert_eq!(vec![extrinsics[0].clone()], data);
 	}
+
+	// Each non-empty blob of a submit data batch should give its own app extrinsic.
+	#[test]
+	fn to_app_extrinsics_filters_correctly_6() {
+		let extrinsics = vec![
+			submit_data(hex!("abcd").to_vec()),
+			submit_data_batch(vec![
+				(1, hex!("0102").to_vec()),
+				(2, vec![]),
+				(3, hex!("0304").to_vec()),
+			]),
+		];
+
+		let app_extrinsics =
+			HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics)
+				.to_app_extrinsics()
+				.into_iter()
+				.map(|app_extrinsic| (app_extrinsic.app_id, app_extrinsic.data))
+				.collect::<Vec<_>>();
+
+		let expected = vec![
+			(AppId(1), extrinsics[0].clone()),
+			(AppId(1), unsigned_submit_data(hex!("0102").to_vec())),
+			(AppId(3), unsigned_submit_data(hex!("0304").to_vec())),
+		];
+		assert_eq!(expected, app_extrinsics);
+	}
+
+	// Each non-empty blob of a submit data batch should give its own data submission leaf.
+	#[test]
+	fn submit_data_batch_leaves() {
+		let extrinsics = vec![submit_data_batch(vec![
+			(1, hex!("0102").to_vec()),
+			(3, hex!("0304").to_vec()),
+		])];
+
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+		let submissions = data
+			.data_submissions
+			.iter()
+			.map(|s| (s.id, s.tx_index, s.data.clone()))
+			.collect::<Vec<_>>();
+
+		let expected = vec![
+			(AppId(1), 0, hex!("0102").to_vec()),
+			(AppId(3), 0, hex!("0304").to_vec()),
+		];
+		assert_eq!(expected, submissions);
+	}
 }
 
 #[cfg(test)]
@@ -558,6 +626,42 @@ mod data_root {
 		assert_eq!(da_proof.number_of_leaves, 4);
 	}
 
+	#[test]
+	fn test_data_proof_of_second_blob() {
+		let extrinsics: Vec<Vec<u8>> = vec![
+			submit_data("0".into()),
+			submit_data_batch(vec![(1, "1".into()), (2, "2".into())]),
+		];
+		let data = HeaderExtensionBuilderData::from_raw_extrinsics::<Runtime>(0, &extrinsics);
+
+		// Same leaves as `test_left_data_proof_with_skipped_tx`.
+		let blob_leaf_idx = |tx_idx, blob_idx| {
+			data.blob_leaf_idx(tx_idx, blob_idx)
+				.map(|(leaf_idx, _)| leaf_idx)
+		};
+		assert_eq!(blob_leaf_idx(1, 0), Some(1));
+		assert_eq!(blob_leaf_idx(1, 1), Some(2));
+		assert_eq!(blob_leaf_idx(1, 2), None);
+		assert_eq!(blob_leaf_idx(0, 1), None);
+		assert_eq!(data.leaf_idx(1).map(|(leaf_idx, _)| leaf_idx), Some(1));
+
+		let leaf_idx = blob_leaf_idx(1, 1).unwrap();
+		let da_proof = data.submitted_proof_of(leaf_idx).unwrap();
+		assert_eq!(da_proof.leaf_index, 2);
+		assert_eq!(da_proof.leaf.as_slice(), keccak_256("2".as_bytes()));
+		assert_eq!(
+			da_proof.root,
+			hex!("877f9ed6aa67f160e9b9b7794bb851998d15b65d11bab3efc6ff444339a3d750").into()
+		);
+		assert_eq!(
+			da_proof.proof,
+			[
+				hex!("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563").into(),
+				hex!("db0ccc7a2d6559682303cc9322d4b79a7ad619f0c87d5f94723a33015550a64e").into()
+			]
+		);
+	}
+
 	#[test]
 	fn test_message_encoding() {
 		let expected_encoded_message = hex!("00000000000000000000000000000000000000000000000000000000000000200200000000000000000000000000000000000000000000000000000000000000681257bed628425a28b469114dc21a7c30205cfd00000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000e00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec();
@@ -1,6 +1,6 @@
 use crate::{
-	constants, prod_or_fast, voter_bags, weights, AccountId, AccountIndex, Babe, Balances, Block,
-	BlockNumber, ElectionProviderMultiPhase, Everything, Hash, Header, Historical, ImOnline,
+	constants, prod_or_fast, voter_bags, weights, AccountId, AccountIndex, Assets, Babe, Balances,
+	Block, BlockNumber, ElectionProviderMultiPhase, Everything, Hash, Header, Historical, ImOnline,
 	ImOnlineId, Index, Indices, Moment, NominationPools, Offences, OriginCaller, PalletInfo,
 	Preimage, ReserveIdentifier, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
 	RuntimeHoldReason, RuntimeOrigin, RuntimeVersion, Session, SessionKeys, Signature,
@@ -12,8 +12,8 @@ use avail_core::{
 	AppId,
 };
 
-use codec::{Decode, Encode, MaxEncodedLen};
-use constants::time::DAYS;
+use codec::{Compact, Decode, Encode, MaxEncodedLen};
+use constants::time::{DAYS, HOURS};
 use frame_election_provider_support::{
 	onchain, BalancingConfig, ElectionDataProvider, SequentialPhragmen, VoteWeight,
 };
@@ -26,13 +26,15 @@ use frame_support::{
 		tokens::{
 			imbalance::ResolveTo, pay::PayFromAccount, Imbalance, UnityAssetBalanceConversion,
 		},
-		ConstU32, Contains, Currency, EitherOf, EitherOfDiverse, EqualPrivilegeOnly, InsideBoth,
-		InstanceFilter, LinearStoragePrice, OnUnbalanced,
+		AsEnsureOriginWithArg, ConstU32, Contains, Currency, EitherOf, EitherOfDiverse,
+		EqualPrivilegeOnly, InsideBoth, InstanceFilter, LinearStoragePrice, OnUnbalanced,
 	},
 	weights::{constants::RocksDbWeight, ConstantMultiplier},
 	PalletId,
 };
-use frame_system::{limits::BlockLength, EnsureRoot, EnsureRootWithSuccess, EnsureWithSuccess};
+use frame_system::{
+	limits::BlockLength, EnsureNever, EnsureRoot, EnsureRootWithSuccess, EnsureWithSuccess,
+};
 use pallet_election_provider_multi_phase::{GeometricDepositBase, SolutionAccuracyOf};
 use pallet_identity::legacy::IdentityInfo;
 use pallet_transaction_payment::{FungibleAdapter, Multiplier, TargetedFeeAdjustment};
@@ -67,6 +69,8 @@ impl pallet_mandate::Config for Runtime {
 
 parameter_types! {
 	pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+	// No pallet handles bridge messages yet, raise it when registering a handler.
+	pub const VectorMaxMessageHandlerWeight: Weight = Weight::zero();
 }
 
 impl pallet_vector::Config for Runtime {
@@ -75,9 +79,55 @@ impl pallet_vector::Config for Runtime {
 	type WeightInfo = weights::pallet_vector::WeightInfo<Runtime>;
 	type TimeProvider = pallet_timestamp::Pallet<Runtime>;
 	type Currency = Balances;
+	type Assets = Assets;
 	type MessageMappingStorageIndex = ConstU64<1>;
 	type PalletId = BridgePalletId;
 	type AvailDomain = ConstU32<1>;
+	// About 30 days of Ethereum slots and 36 days of sync committee periods.
+	type SlotRetention = ConstU64<216_000>;
+	type PeriodRetention = ConstU64<32>;
+	type TransferCapPeriod = ConstU32<DAYS>;
+	type OutboundMessageRetention = ConstU32<{ 30 * DAYS }>;
+	type MessageHandler = ();
+	type MaxMessageHandlerWeight = VectorMaxMessageHandlerWeight;
+	type MaxUpdaters = ConstU32<8>;
+	// Report the bridge as stalled after six hours without a head update.
+	type UpdaterLivenessPeriod = ConstU32<{ 6 * HOURS }>;
+	// Leaves bridge users two days to exit before a key or updater rotation is applied.
+	type ConfigChangeDelay = ConstU32<{ 2 * DAYS }>;
+}
+
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * AVAIL;
+	pub const AssetAccountDeposit: Balance = AVAIL;
+	pub const AssetApprovalDeposit: Balance = AVAIL;
+	pub const AssetMetadataDepositBase: Balance = 10 * AVAIL;
+	pub const AssetMetadataDepositPerByte: Balance = AVAIL;
+	pub const AssetStringLimit: u32 = 50;
+}
+
+impl pallet_assets::Config for Runtime {
+	type ApprovalDeposit = AssetApprovalDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type AssetDeposit = AssetDeposit;
+	type AssetId = u32;
+	type AssetIdParameter = Compact<u32>;
+	type Balance = Balance;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type CallbackHandle = ();
+	/// Bridged assets are only created by governance, through `force_create`.
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureNever<AccountId>>;
+	type Currency = Balances;
+	type Extra = ();
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Freezer = ();
+	type MetadataDepositBase = AssetMetadataDepositBase;
+	type MetadataDepositPerByte = AssetMetadataDepositPerByte;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = AssetStringLimit;
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -117,14 +167,25 @@ impl pallet_identity::Config for Runtime {
 	type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const DaControlPalletId: PalletId = PalletId(*b"avl/dact");
+}
+
 impl da_control::Config for Runtime {
+	type AppKeyDeposit = constants::da::AppKeyDeposit;
+	type AppMetadataDepositPerByte = constants::da::AppMetadataDepositPerByte;
 	type BlockLenProposalId = u32;
+	type Currency = Balances;
+	type GridUtilizationWindow = constants::da::GridUtilizationWindow;
 	type MaxAppDataLength = constants::da::MaxAppDataLength;
 	type MaxAppKeyLength = constants::da::MaxAppKeyLength;
+	type MaxAppMetadataLength = constants::da::MaxAppMetadataLength;
 	type MaxBlockCols = constants::da::MaxBlockCols;
 	type MaxBlockRows = constants::da::MaxBlockRows;
+	type MaxSponsoredFee = constants::da::MaxSponsoredFee;
 	type MinBlockCols = constants::da::MinBlockCols;
 	type MinBlockRows = constants::da::MinBlockRows;
+	type PalletId = DaControlPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_dactr::WeightInfo<Runtime>;
 }
@@ -907,8 +968,10 @@ where
 			frame_system::CheckEra::<Runtime>::from(era),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
-			da_control::CheckAppId::<Runtime>::from(AppId(0)),
+			da_control::ChargeAppTransactionPayment::<Runtime>::new(
+				pallet_transaction_payment::ChargeTransactionPayment::from(tip),
+				da_control::CheckAppId::from(AppId(0)),
+			),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -4,7 +4,7 @@ pub mod runtime;
 #[cfg(feature = "std")]
 use kate::gridgen::core::CellBlock;
 // Reexport
-pub use runtime::{grid, multiproof, proof};
+pub use runtime::{app_data, grid, multiproof, proof};
 
 use codec::{Decode, Encode};
 use core::num::TryFromIntError;
@@ -44,6 +44,18 @@ impl GCellBlock {
 	}
 }
 
+/// Row of the evaluation grid which contains data of an application, with the proofs of its
+/// cells against the block commitments.
+#[derive(Encode, Decode, TypeInfo, Debug, Clone)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+pub struct GAppRow {
+	/// Index of the row in the original (non-extended) grid.
+	pub row: u32,
+	pub data: GRow,
+	/// Proof of each cell of `data`, in the same order.
+	pub proofs: Vec<GProof>,
+}
+
 /// # NOTE
 /// `Serde` requires a custom implementation for `GProof` due to the array size (greater than `[T;32]`).
 /// In this case, we transform into a `Vec<u8>` as intermediate step to serialize/deserialize.
@@ -149,8 +149,9 @@ pub trait HostedKate {
 
 		// let orig_dims = non_extended_dims(grid.dims()).ok_or(Error::InvalidDimension)?;
 		let dims = grid.dims();
+		// The application has no data in this block.
 		let Some(rows) = grid.app_rows(AppId(app_id), Some(dims))? else {
-			return Err(Error::AppRow);
+			return Ok(Vec::new());
 		};
 
 		let mut all_rows = vec![None; dims.height()];
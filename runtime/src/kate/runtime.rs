@@ -50,3 +50,12 @@ pub fn multiproof<T: SystemConfig>(
 	let seed = random_seed::<T>();
 	hosted_kate::multiproof(app_extrinsics, block_len, seed, cells)
 }
+
+pub fn app_data<T: SystemConfig>(
+	app_extrinsics: Vec<AppExtrinsic>,
+	block_length: BlockLength,
+	app_id: u32,
+) -> Result<Vec<Option<GRow>>, Error> {
+	let seed = random_seed::<T>();
+	hosted_kate::app_data(app_extrinsics, block_length, seed, app_id)
+}
@@ -149,6 +149,7 @@ construct_runtime!(
 		Proxy: pallet_proxy = 40,
 		TxPause: pallet_tx_pause = 41,
 		TreasuryCommittee: pallet_collective::<Instance1> = 42,
+		Assets: pallet_assets = 43,
 	}
 );
 
@@ -299,6 +300,25 @@ mod tests {
 		);
 	}
 
+	#[cfg(not(feature = "vector-mock"))]
+	#[test]
+	fn vector_mock_is_not_in_metadata() {
+		use codec::Encode;
+
+		let metadata = Runtime::metadata().encode();
+		for item in [
+			"MockEnabled",
+			"MockIsNotEnabled",
+			"mock_fulfill",
+			"enable_mock",
+		] {
+			let found = metadata
+				.windows(item.len())
+				.any(|window| window == item.as_bytes());
+			assert!(!found, "{item} is in the runtime metadata");
+		}
+	}
+
 	#[test]
 	fn validate_transaction_submitter_bounds() {
 		fn is_submit_signed_transaction<T>()
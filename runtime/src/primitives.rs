@@ -1,7 +1,7 @@
 use avail_core::{asdr::AppUncheckedExtrinsic, header::Header as DaHeader, OpaqueExtrinsic};
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{BlakeTwo256, IdentifyAccount, Verify},
+	traits::{BlakeTwo256, ConstU32, IdentifyAccount, Verify},
 	MultiSignature,
 };
 use sp_std::vec::Vec;
@@ -57,8 +57,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
-	da_control::CheckAppId<Runtime>,
+	da_control::ChargeAppTransactionPayment<Runtime>,
 );
 
 /// The payload being signed in transactions.
@@ -72,8 +71,16 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Runtime migrations executed on the next runtime upgrade.
+pub type Migrations = (
+	// Application ids 0 to 9 are the genesis keys.
+	da_control::migrations::v1::MigrateToV1<Runtime, ConstU32<10>>,
+	da_control::migrations::v2::MigrateToV2<Runtime>,
+);
+
 /// ID type for named reserves.
 pub type ReserveIdentifier = [u8; 8];
 
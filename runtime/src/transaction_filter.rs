@@ -8,16 +8,17 @@ use avail_core::{
 	AppExtrinsic, AppId, OpaqueExtrinsic,
 };
 
-use da_control::Call as DACall;
+use codec::Encode;
+use da_control::{AppDataFor, Call as DACall};
 use pallet_multisig::Call as MultisigCall;
 use pallet_proxy::Call as ProxyCall;
 use pallet_vector::Call as VectorCall;
 use sp_core::H256;
-use sp_std::vec::Vec;
+use sp_std::{vec, vec::Vec};
 
 const MAX_FILTER_ITERATIONS: usize = 3;
 
-/// Filters and extracts `data` from `call` if it is a `DataAvailability::submit_data` or `Vector::send_message` type.
+/// Filters and extracts `data` from `call` if it is a `DataAvailability::submit_data`, `DataAvailability::submit_data_batch` or `Vector::send_message` type.
 /// Handles N levels of nesting in case those calls are wrapped in proxy / multisig calls.
 impl HeaderExtensionDataFilter for Runtime {
 	fn filter(
@@ -74,32 +75,68 @@ impl HeaderExtensionDataFilter for Runtime {
 }
 
 /// Filters and extracts `data` from `calls` if internal data is not empty.
+///
+/// Each blob of `DataAvailability::submit_data_batch` is extracted as its own `SubmittedData`
+/// leaf and `AppExtrinsic`, using the `AppId` of the blob.
+///
+/// The `AppExtrinsic` of `DataAvailability::submit_data` contains the whole encoded extrinsic.
+/// The signature and the other blobs of a batch belong to other applications, so each blob is
+/// encoded as the unsigned `DataAvailability::submit_data` extrinsic carrying it instead: the grid
+/// rows of every application decode as extrinsics.
 fn filter_da_call(
 	app_extrinsic: AppExtrinsic,
 	call: &DACall<Runtime>,
 	app_id: AppId,
 	tx_index: usize,
 ) -> Option<ExtractedTxData> {
-	let DACall::submit_data { data } = call else {
-		return None;
-	};
+	let tx_index = u32::try_from(tx_index).ok()?;
 
-	if data.is_empty() {
-		return None;
-	}
+	match call {
+		DACall::submit_data { data } => {
+			if data.is_empty() {
+				return None;
+			}
 
-	let tx_index = u32::try_from(tx_index).ok()?;
-	let submitted_data = Some(SubmittedData::new(
-		app_id,
-		tx_index,
-		data.as_slice().to_vec(),
-	));
+			let submitted_data = SubmittedData::new(app_id, tx_index, data.as_slice().to_vec());
+			Some(ExtractedTxData {
+				submitted_data: vec![submitted_data],
+				app_extrinsics: vec![app_extrinsic],
+				..Default::default()
+			})
+		},
+		DACall::submit_data_batch { blobs } => {
+			let (submitted_data, app_extrinsics): (Vec<_>, Vec<_>) = blobs
+				.iter()
+				.filter(|(_, data)| !data.is_empty())
+				.map(|(id, data)| {
+					let submitted_data =
+						SubmittedData::new(*id, tx_index, data.as_slice().to_vec());
+					let app_extrinsic = AppExtrinsic {
+						app_id: *id,
+						data: blob_extrinsic(data.clone()),
+					};
+					(submitted_data, app_extrinsic)
+				})
+				.unzip();
+
+			if submitted_data.is_empty() {
+				return None;
+			}
 
-	Some(ExtractedTxData {
-		submitted_data,
-		app_extrinsic: Some(app_extrinsic),
-		..Default::default()
-	})
+			Some(ExtractedTxData {
+				submitted_data,
+				app_extrinsics,
+				..Default::default()
+			})
+		},
+		_ => None,
+	}
+}
+
+/// Encodes `data` as the unsigned `DataAvailability::submit_data` extrinsic carrying it.
+fn blob_extrinsic(data: AppDataFor<Runtime>) -> Vec<u8> {
+	let function = Call::DataAvailability(DACall::submit_data { data });
+	UncheckedExtrinsic::new_unsigned(function).encode()
 }
 
 /// Filters and extracts message references from `call`
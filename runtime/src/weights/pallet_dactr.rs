@@ -131,4 +131,222 @@ impl<T: frame_system::Config> da_control::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppMetadataOf` (r:1 w:1)
+	/// Proof: `DataAvailability::AppMetadataOf` (`max_values`: None, `max_size`: Some(419), added: 2894, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_application_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6196`
+		// Minimum execution time: 20_112_000 picoseconds.
+		Weight::from_parts(20_764_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:1)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppMetadataOf` (r:1 w:1)
+	/// Proof: `DataAvailability::AppMetadataOf` (`max_values`: None, `max_size`: Some(419), added: 2894, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::RetiredAppIds` (r:0 w:1)
+	/// Proof: `DataAvailability::RetiredAppIds` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppIdToKey` (r:0 w:1)
+	/// Proof: `DataAvailability::AppIdToKey` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn retire_application_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6196`
+		// Minimum execution time: 22_467_000 picoseconds.
+		Weight::from_parts(23_185_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `DataAvailability::AppDataQuotas` (r:0 w:1)
+	/// Proof: `DataAvailability::AppDataQuotas` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn set_app_data_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_814_000 picoseconds.
+		Weight::from_parts(8_156_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::SubmitDataFeeModifierByApp` (r:0 w:1)
+	/// Proof: `DataAvailability::SubmitDataFeeModifierByApp` (`max_values`: None, `max_size`: Some(39), added: 2514, mode: `MaxEncodedLen`)
+	fn set_submit_data_fee_modifier_by_app() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_121_000 picoseconds.
+		Weight::from_parts(8_493_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fund_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `415`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_274_000 picoseconds.
+		Weight::from_parts(62_905_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `518`
+		//  Estimated: `6196`
+		// Minimum execution time: 60_318_000 picoseconds.
+		Weight::from_parts(61_842_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::SponsoredAllowances` (r:0 w:1)
+	/// Proof: `DataAvailability::SponsoredAllowances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn set_sponsored_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3599`
+		// Minimum execution time: 17_926_000 picoseconds.
+		Weight::from_parts(18_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 3599))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::SponsoredAllowances` (r:1 w:1)
+	/// Proof: `DataAvailability::SponsoredAllowances` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn revoke_sponsored_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `401`
+		//  Estimated: `3599`
+		// Minimum execution time: 22_104_000 picoseconds.
+		Weight::from_parts(22_771_000, 0)
+			.saturating_add(Weight::from_parts(0, 3599))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::PendingBlockLength` (r:1 w:1)
+	/// Proof: `DataAvailability::PendingBlockLength` (`max_values`: Some(1), `max_size`: Some(28), added: 523, mode: `MaxEncodedLen`)
+	fn schedule_block_length_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1513`
+		// Minimum execution time: 10_350_000 picoseconds.
+		Weight::from_parts(10_721_000, 0)
+			.saturating_add(Weight::from_parts(0, 1513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::PendingBlockLength` (r:1 w:1)
+	/// Proof: `DataAvailability::PendingBlockLength` (`max_values`: Some(1), `max_size`: Some(28), added: 523, mode: `MaxEncodedLen`)
+	fn cancel_block_length_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94`
+		//  Estimated: `1513`
+		// Minimum execution time: 11_207_000 picoseconds.
+		Weight::from_parts(11_592_000, 0)
+			.saturating_add(Weight::from_parts(0, 1513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAvailability::GridUtilization` (r:0 w:1)
+	/// Proof: `DataAvailability::GridUtilization` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::GridAutoResize` (r:0 w:1)
+	/// Proof: `DataAvailability::GridAutoResize` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_grid_auto_resize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_870_000 picoseconds.
+		Weight::from_parts(10_215_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppMetadataOf` (r:1 w:1)
+	/// Proof: `DataAvailability::AppMetadataOf` (`max_values`: None, `max_size`: Some(419), added: 2894, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn set_app_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3884`
+		// Minimum execution time: 27_816_000 picoseconds.
+		Weight::from_parts(28_542_000, 0)
+			.saturating_add(Weight::from_parts(0, 3884))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::AppKeys` (r:1 w:0)
+	/// Proof: `DataAvailability::AppKeys` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::AppMetadataOf` (r:1 w:1)
+	/// Proof: `DataAvailability::AppMetadataOf` (`max_values`: None, `max_size`: Some(419), added: 2894, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn clear_app_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `497`
+		//  Estimated: `3884`
+		// Minimum execution time: 26_390_000 picoseconds.
+		Weight::from_parts(27_104_000, 0)
+			.saturating_add(Weight::from_parts(0, 3884))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::PendingBlockLength` (r:1 w:1)
+	/// Proof: `DataAvailability::PendingBlockLength` (`max_values`: Some(1), `max_size`: Some(28), added: 523, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:0 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	fn enact_pending_block_length() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94`
+		//  Estimated: `1513`
+		// Minimum execution time: 11_012_000 picoseconds.
+		Weight::from_parts(11_468_000, 0)
+			.saturating_add(Weight::from_parts(0, 1513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAvailability::GridAutoResize` (r:1 w:0)
+	/// Proof: `DataAvailability::GridAutoResize` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `DataAvailability::GridUtilization` (r:1 w:1)
+	/// Proof: `DataAvailability::GridUtilization` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// Storage: `System::DynamicBlockLength` (r:1 w:1)
+	/// Proof: `System::DynamicBlockLength` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	/// Storage: `System::AllExtrinsicsLen` (r:1 w:0)
+	/// Proof: `System::AllExtrinsicsLen` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[0, 10]`.
+	fn auto_resize_block_length(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `129`
+		//  Estimated: `1614`
+		// Minimum execution time: 14_207_000 picoseconds.
+		Weight::from_parts(14_936_000, 0)
+			.saturating_add(Weight::from_parts(0, 1614))
+			// Standard Error: 1
+			.saturating_add(Weight::from_parts(181_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -329,5 +329,337 @@ impl<T: frame_system::Config> pallet_vector::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Vector::RegisteredAssets` (r:1 w:1)
+	/// Proof: `Vector::RegisteredAssets` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn register_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3501`
+		// Minimum execution time: 9_112_000 picoseconds.
+		Weight::from_parts(9_487_000, 0)
+			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::RegisteredAssets` (r:1 w:1)
+	/// Proof: `Vector::RegisteredAssets` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn deregister_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79`
+		//  Estimated: `3501`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_650_000, 0)
+			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PrunedSlotsBelow` (r:1 w:0)
+	/// Proof: `Vector::PrunedSlotsBelow` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn verify_account_proof() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147`
+		//  Estimated: `3505`
+		// Minimum execution time: 61_325_000 picoseconds.
+		Weight::from_parts(62_708_000, 0)
+			.saturating_add(Weight::from_parts(0, 3505))
+			.saturating_add(T::DbWeight::get().reads(4))
+	}
+	/// Storage: `Vector::FulfilledSlots` (r:1 w:1)
+	/// Proof: `Vector::FulfilledSlots` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Head` (r:1 w:0)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PrunedSlotsBelow` (r:1 w:1)
+	/// Proof: `Vector::PrunedSlotsBelow` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:0 w:1)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:1)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:0 w:1)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn prune_slot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `211`
+		//  Estimated: `3481`
+		// Minimum execution time: 14_231_000 picoseconds.
+		Weight::from_parts(14_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 3481))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Vector::Head` (r:1 w:0)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
+	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SyncCommitteePoseidons` (r:0 w:1)
+	/// Proof: `Vector::SyncCommitteePoseidons` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SyncCommitteeHashes` (r:0 w:1)
+	/// Proof: `Vector::SyncCommitteeHashes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn prune_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `128`
+		//  Estimated: `1498`
+		// Minimum execution time: 8_054_000 picoseconds.
+		Weight::from_parts(8_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 1498))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Vector::Head` (r:1 w:0)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
+	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PrunedSlotsBelow` (r:1 w:1)
+	/// Proof: `Vector::PrunedSlotsBelow` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:0 w:1024)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:1024)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:0 w:1024)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SyncCommitteePoseidons` (r:0 w:1024)
+	/// Proof: `Vector::SyncCommitteePoseidons` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SyncCommitteeHashes` (r:0 w:1024)
+	/// Proof: `Vector::SyncCommitteeHashes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 1024]`.
+	/// The range of component `p` is `[0, 1024]`.
+	fn prune_light_client_state(s: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `128`
+		//  Estimated: `1498`
+		// Minimum execution time: 9_320_000 picoseconds.
+		Weight::from_parts(9_815_000, 0)
+			.saturating_add(Weight::from_parts(0, 1498))
+			// Standard Error: 1
+			.saturating_add(Weight::from_parts(3_208_000, 0).saturating_mul(s.into()))
+			// Standard Error: 1
+			.saturating_add(Weight::from_parts(2_187_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+	}
+	/// Storage: `Vector::TransferCaps` (r:0 w:1)
+	/// Proof: `Vector::TransferCaps` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn set_transfer_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_803_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::PausedTransfers` (r:0 w:1)
+	/// Proof: `Vector::PausedTransfers` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::TransferUsage` (r:0 w:1)
+	/// Proof: `Vector::TransferUsage` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	fn resume_transfers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_925_000 picoseconds.
+		Weight::from_parts(9_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Vector::ExecutionFees` (r:0 w:1)
+	/// Proof: `Vector::ExecutionFees` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn set_execution_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_538_000 picoseconds.
+		Weight::from_parts(7_921_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::ProofSystems` (r:0 w:1)
+	/// Proof: `Vector::ProofSystems` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextConfigChangeId` (r:1 w:1)
+	/// Proof: `Vector::NextConfigChangeId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PendingConfigChanges` (r:0 w:1)
+	/// Proof: `Vector::PendingConfigChanges` (`max_values`: None, `max_size`: Some(40011), added: 42486, mode: `MaxEncodedLen`)
+	fn set_proof_system() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_786_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Vector::MessageStatus` (r:1 w:1)
+	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PrunedSlotsBelow` (r:1 w:0)
+	/// Proof: `Vector::PrunedSlotsBelow` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:1 w:0)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BroadcasterLogTopics` (r:1 w:0)
+	/// Proof: `Vector::BroadcasterLogTopics` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::RegisteredAssets` (r:1 w:0)
+	/// Proof: `Vector::RegisteredAssets` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PausedTransfers` (r:1 w:0)
+	/// Proof: `Vector::PausedTransfers` (`max_values`: None, `max_size`: Some(10), added: 2485, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::TransferCaps` (r:1 w:0)
+	/// Proof: `Vector::TransferCaps` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::TransferUsage` (r:1 w:1)
+	/// Proof: `Vector::TransferUsage` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionFees` (r:1 w:0)
+	/// Proof: `Vector::ExecutionFees` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn execute_via_receipt_fungible_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `41487`
+		// Minimum execution time: 176_318_000 picoseconds.
+		Weight::from_parts(181_042_000, 0)
+			.saturating_add(Weight::from_parts(0, 41487))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Vector::MessageStatus` (r:1 w:1)
+	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PrunedSlotsBelow` (r:1 w:0)
+	/// Proof: `Vector::PrunedSlotsBelow` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:1 w:0)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BroadcasterLogTopics` (r:1 w:0)
+	/// Proof: `Vector::BroadcasterLogTopics` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionFees` (r:1 w:0)
+	/// Proof: `Vector::ExecutionFees` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 102400]`.
+	fn execute_via_receipt_arbitrary_message(_l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `462`
+		//  Estimated: `41487`
+		// Minimum execution time: 101_905_000 picoseconds.
+		Weight::from_parts(105_377_412, 0)
+			.saturating_add(Weight::from_parts(0, 41487))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Vector::BroadcasterLogTopics` (r:0 w:1)
+	/// Proof: `Vector::BroadcasterLogTopics` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_broadcaster_log_topic() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_364_000 picoseconds.
+		Weight::from_parts(7_702_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Vector::OutboundMessageIds` (r:1 w:1)
+	/// Proof: `Vector::OutboundMessageIds` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OutboundMessages` (r:1 w:1)
+	/// Proof: `Vector::OutboundMessages` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	fn prune_outbound_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `228`
+		//  Estimated: `3555`
+		// Minimum execution time: 12_163_000 picoseconds.
+		Weight::from_parts(12_541_000, 0)
+			.saturating_add(Weight::from_parts(0, 3555))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Vector::Updater` (r:1 w:0)
+	/// Proof: `Vector::Updater` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Updaters` (r:1 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: Some(1), `max_size`: Some(257), added: 752, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextConfigChangeId` (r:1 w:1)
+	/// Proof: `Vector::NextConfigChangeId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PendingConfigChanges` (r:0 w:1)
+	/// Proof: `Vector::PendingConfigChanges` (`max_values`: None, `max_size`: Some(40011), added: 42486, mode: `MaxEncodedLen`)
+	fn add_updater() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `313`
+		//  Estimated: `1742`
+		// Minimum execution time: 9_874_000 picoseconds.
+		Weight::from_parts(10_362_000, 0)
+			.saturating_add(Weight::from_parts(0, 1742))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Vector::Updaters` (r:1 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: Some(1), `max_size`: Some(257), added: 752, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextConfigChangeId` (r:1 w:1)
+	/// Proof: `Vector::NextConfigChangeId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PendingConfigChanges` (r:0 w:1)
+	/// Proof: `Vector::PendingConfigChanges` (`max_values`: None, `max_size`: Some(40011), added: 42486, mode: `MaxEncodedLen`)
+	fn remove_updater() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `313`
+		//  Estimated: `1742`
+		// Minimum execution time: 8_617_000 picoseconds.
+		Weight::from_parts(9_043_000, 0)
+			.saturating_add(Weight::from_parts(0, 1742))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Vector::UpdaterSelectionStorage` (r:0 w:1)
+	/// Proof: `Vector::UpdaterSelectionStorage` (`max_values`: Some(1), `max_size`: Some(6), added: 501, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextConfigChangeId` (r:1 w:1)
+	/// Proof: `Vector::NextConfigChangeId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::PendingConfigChanges` (r:0 w:1)
+	/// Proof: `Vector::PendingConfigChanges` (`max_values`: None, `max_size`: Some(40011), added: 42486, mode: `MaxEncodedLen`)
+	fn set_updater_selection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_212_000 picoseconds.
+		Weight::from_parts(5_498_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Vector::PendingConfigChanges` (r:1 w:1)
+	/// Proof: `Vector::PendingConfigChanges` (`max_values`: None, `max_size`: Some(40011), added: 42486, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::WhitelistedDomains` (r:0 w:1)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	fn apply_config_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `40146`
+		//  Estimated: `43476`
+		// Minimum execution time: 48_310_000 picoseconds.
+		Weight::from_parts(49_872_000, 0)
+			.saturating_add(Weight::from_parts(0, 43476))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Vector::PendingConfigChanges` (r:1 w:1)
+	/// Proof: `Vector::PendingConfigChanges` (`max_values`: None, `max_size`: Some(40011), added: 42486, mode: `MaxEncodedLen`)
+	fn cancel_config_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `40146`
+		//  Estimated: `43476`
+		// Minimum execution time: 31_254_000 picoseconds.
+		Weight::from_parts(32_104_000, 0)
+			.saturating_add(Weight::from_parts(0, 43476))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 